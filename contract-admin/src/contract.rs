@@ -1,41 +1,169 @@
 use crate::error::ContractError;
-use crate::state::{ADMINS, DONATION_DENOM, QUORUM, VOTE_CODE_ID};
+use crate::migration;
+use crate::rewards;
+use crate::state::{
+    AdminInfo, ADMINS, ADMINS_COUNT, DONATION_ASSETS, DONATION_DENOM, GUARDIAN, MIN_ADMINS, PAUSED,
+    PROPOSAL_DEPOSIT, REMAINDER_POLICY, THRESHOLD, TIMELOCK, TOTAL_WEIGHT, VETO, VOTE_CODE_ID,
+    VOTING_PERIOD,
+};
 use cosmwasm_std::{
-    coins, to_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError,
-    StdResult,
+    coin, to_json_binary, Addr, Api, BankMsg, Binary, Decimal, Deps, DepsMut, Env, Event,
+    MessageInfo, Response, StdError, StdResult, Storage, Timestamp,
 };
 
-use msgs::admin::{AdminsListResp, ExecuteMsg, InstantiateMsg, JoinTimeResp, QueryMsg};
-use msgs::vote::InstantiateMsg as VoteInstantiate;
+use msgs::admin::{
+    AdminsListResp, Asset, AssetInfo, ConfigResp, DonationAssetsResp, DonationsResp, ExecuteMsg,
+    InstantiateMsg, JoinTimeResp, MigrateMsg, PendingRewardsResp, ProposalAction, QueryMsg,
+    RemainderPolicy,
+};
+use msgs::events::{self, attributes};
+use msgs::vote::{InstantiateMsg as VoteInstantiate, VoteOutcome};
 
 pub const VOTE_INSTANTIATE_ID: u64 = 1;
+pub const REMOVE_VOTER_ID: u64 = 2;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+// proposals inspected by a single filtered `ListProposals` query
+const MAX_SCAN: usize = MAX_LIMIT as usize * 10;
+
+pub const CONTRACT_NAME: &str = "crates.io:contract-admin";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub fn instantiate(
     deps: DepsMut,
     env: Env,
     _info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
-    for addr in msg.admins.into_iter() {
-        ADMINS.save(
-            deps.storage,
-            deps.api.addr_validate(&addr)?,
-            &env.block.time,
-        )?;
+) -> Result<Response, ContractError> {
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let mut admins_count = 0;
+    let mut total_weight = 0;
+    let mut added = vec![];
+    for member in msg.admins.into_iter() {
+        let addr = deps.api.addr_validate(&member.addr)?;
+        if ADMINS.has(deps.storage, addr.clone()) {
+            continue;
+        }
+        let info = AdminInfo {
+            joined: env.block.time,
+            weight: member.weight,
+        };
+        ADMINS.save(deps.storage, addr.clone(), &info)?;
+        rewards::register(deps.storage, &addr)?;
+        admins_count += 1;
+        total_weight += member.weight;
+        added.push(admin_added(&addr, member.weight));
     }
+    let min_admins = msg.min_admins.unwrap_or(1);
+    if min_admins == 0 {
+        return Err(ContractError::ZeroMinAdmins {});
+    }
+    if admins_count < min_admins {
+        return Err(ContractError::MinAdmins { min: min_admins });
+    }
+    MIN_ADMINS.save(deps.storage, &min_admins)?;
+    if let Some(policy) = msg.remainder_policy {
+        REMAINDER_POLICY.save(deps.storage, &validate_remainder_policy(deps.api, policy)?)?;
+    }
+    ADMINS_COUNT.save(deps.storage, &admins_count)?;
+    TOTAL_WEIGHT.save(deps.storage, &total_weight)?;
     DONATION_DENOM.save(deps.storage, &msg.donation_denom)?;
+    DONATION_ASSETS.save(
+        deps.storage,
+        &validate_assets(deps.api, msg.donation_assets)?,
+    )?;
     VOTE_CODE_ID.save(deps.storage, &msg.vote_code_id)?;
-    QUORUM.save(deps.storage, &msg.quorum)?;
+    exec::validate_threshold(&msg.threshold, total_weight)?;
+    THRESHOLD.save(deps.storage, &msg.threshold)?;
+    VOTING_PERIOD.save(deps.storage, &msg.voting_period)?;
+    if let Some(deposit) = msg.proposal_deposit {
+        PROPOSAL_DEPOSIT.save(deps.storage, &deposit)?;
+    }
+    if let Some(veto) = msg.veto {
+        VETO.save(deps.storage, &deps.api.addr_validate(&veto)?)?;
+    }
+    if let Some(timelock) = msg.timelock {
+        TIMELOCK.save(deps.storage, &timelock)?;
+    }
+    if let Some(guardian) = msg.guardian {
+        GUARDIAN.save(deps.storage, &deps.api.addr_validate(&guardian)?)?;
+    }
 
-    Ok(Response::new())
+    Ok(Response::new().add_events(added))
 }
 
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    let cancelled = match cw2::CONTRACT.may_load(deps.storage)? {
+        Some(previous) => {
+            msgs::migration::validate_version(&previous, CONTRACT_NAME, CONTRACT_VERSION)?;
+            vec![]
+        }
+        None => migration::from_legacy(deps.storage, &env, msg)?,
+    };
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let resp = cancelled.into_iter().fold(
+        Response::new().add_attribute("action", "migrate"),
+        |resp, vote| resp.add_attribute("cancelled_legacy_vote", vote),
+    );
+    Ok(resp)
+}
+
+fn admin_added(admin: &Addr, weight: u64) -> Event {
+    Event::new(events::ADMIN_ADDED)
+        .add_attribute(attributes::ADMIN, admin)
+        .add_attribute(attributes::WEIGHT, weight.to_string())
+}
+
+fn validate_assets(api: &dyn Api, assets: Vec<AssetInfo>) -> StdResult<Vec<AssetInfo>> {
+    assets
+        .into_iter()
+        .map(|asset| match asset {
+            AssetInfo::Cw20(addr) => Ok(AssetInfo::Cw20(api.addr_validate(&addr)?.into_string())),
+            native => Ok(native),
+        })
+        .collect()
+}
+
+fn validate_remainder_policy(api: &dyn Api, policy: RemainderPolicy) -> StdResult<RemainderPolicy> {
+    match policy {
+        RemainderPolicy::Treasury { addr } => Ok(RemainderPolicy::Treasury {
+            addr: api.addr_validate(&addr)?.into_string(),
+        }),
+        policy => Ok(policy),
+    }
+}
+
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     use QueryMsg::*;
 
     match msg {
-        AdminsList {} => to_binary(&query::admins_list(deps)?),
-        JoinTime { admin } => to_binary(&query::join_time(deps, admin)?),
+        AdminsList { start_after, limit } => {
+            to_json_binary(&query::admins_list(deps, start_after, limit)?)
+        }
+        Admins { start_after, limit } => to_json_binary(&query::admins(deps, start_after, limit)?),
+        AdminsCount {} => to_json_binary(&query::admins_count(deps)?),
+        JoinTime { admin } => to_json_binary(&query::join_time(deps, admin)?),
+        VotingPower { admin } => to_json_binary(&query::voting_power(deps, admin)?),
+        TotalPower {} => to_json_binary(&query::total_power(deps)?),
+        Config {} => to_json_binary(&query::config(deps)?),
+        PendingRewards { admin } => to_json_binary(&query::pending_rewards(deps, admin)?),
+        DonationAssets {} => to_json_binary(&query::donation_assets(deps)?),
+        Donations {} => to_json_binary(&query::donations(deps)?),
+        ListProposals {
+            status,
+            start_after,
+            limit,
+        } => to_json_binary(&query::list_proposals(
+            deps,
+            env,
+            status,
+            start_after,
+            limit,
+        )?),
+        Proposal { id } => to_json_binary(&query::proposal(deps, env, id)?),
     }
 }
 
@@ -48,148 +176,1174 @@ pub fn execute(
     use ExecuteMsg::*;
 
     match msg {
-        AddMember {} => exec::add_member(deps, env, info),
+        VoteFinished { outcome } => exec::vote_finished(deps, env, info, outcome),
+        VoteQueued { executable_at } => exec::vote_queued(deps, info, executable_at),
         ProposeAdmin {
             addr,
             admin_code_id,
-        } => exec::propose_admin(deps, info, addr, admin_code_id),
-        Leave {} => exec::leave(deps, info).map_err(Into::into),
+            voting_period,
+        } => exec::propose(
+            deps,
+            env,
+            info,
+            ProposalAction::AddAdmin { addr },
+            admin_code_id,
+            voting_period,
+        ),
+        Propose {
+            action,
+            admin_code_id,
+            voting_period,
+        } => exec::propose(deps, env, info, action, admin_code_id, voting_period),
+        Leave {} => exec::leave(deps, env, info),
         Donate {} => exec::donate(deps, info),
+        Receive(msg) => exec::receive(deps, info, msg),
+        ClaimRewards {} => exec::claim_rewards(deps, info),
+        ClearVote { vote } => exec::clear_vote(deps, vote),
+        Veto { vote } => exec::veto(deps, info, vote),
+        SetPaused { paused } => exec::set_paused(deps, info, paused),
     }
 }
 
 pub mod exec {
+    use cosmwasm_std::from_json;
     use cosmwasm_std::Addr;
     use cosmwasm_std::SubMsg;
     use cosmwasm_std::SubMsgResult;
-    use cw_utils::parse_instantiate_response_data;
-    use msgs::admin::ProposeAdminResp;
+    use cosmwasm_std::Uint128;
+    use cosmwasm_std::{BlockInfo, Empty, Order};
+    use cw20::Cw20ReceiveMsg;
+    use cw_storage_plus::PrefixBound;
+    use cw_utils::{parse_instantiate_response_data, Duration, Expiration, PaymentError};
+    use msgs::admin::{Member, ProposeAdminResp, ReceiveMsg, Threshold};
+    use msgs::client::VoteContract;
+    use msgs::vote::VoteStatus;
 
-    use crate::state::vote::PROPOSED_ADMIN;
-    use crate::state::PENDING_VOTES;
+    use crate::state::{
+        Deposit, Proposal, DEPOSITS, DEPOSIT_IN_FLIGHT, OPEN_VOTES_BY_HEIGHT, OPEN_VOTES_BY_TIME,
+        PENDING_VOTES, PROPOSALS, PROPOSALS_COUNT, PROPOSER_IN_FLIGHT,
+    };
+    use msgs::storage::vote::{ACTION, EXPIRATION};
 
     use super::*;
     use cosmwasm_std::WasmMsg;
 
-    pub fn add_member(
-        deps: DepsMut,
+    pub fn vote_finished(
+        mut deps: DepsMut,
         env: Env,
         info: MessageInfo,
+        outcome: VoteOutcome,
+    ) -> Result<Response, ContractError> {
+        let id = pending_proposal(deps.storage, &info.sender)?;
+        if outcome == VoteOutcome::Passed {
+            // Reverting the report keeps the vote open, so it can pass again once unpaused
+            let action = PROPOSALS.load(deps.storage, id)?.action;
+            if !allowed_while_paused(&action) {
+                ensure_not_paused(deps.storage)?;
+            }
+        }
+        // Vote can report its outcome only once
+        PENDING_VOTES.remove(deps.storage, info.sender.clone());
+        let proposal = set_proposal_status(deps.storage, id, outcome.clone().into())?;
+        unindex_open_vote(deps.storage, &info.sender, &proposal.expires);
+
+        let event = match outcome {
+            VoteOutcome::Passed => events::VOTE_PASSED,
+            VoteOutcome::Rejected => events::VOTE_REJECTED,
+            VoteOutcome::Expired => events::VOTE_EXPIRED,
+            VoteOutcome::Cancelled => events::VOTE_CANCELLED,
+            VoteOutcome::Vetoed => events::VOTE_VETOED,
+        };
+        let event = Event::new(event)
+            .add_attribute(attributes::PROPOSAL_ID, id.to_string())
+            .add_attribute(attributes::VOTE, info.sender.as_str());
+
+        let resp = match outcome {
+            VoteOutcome::Passed => apply_action(deps.branch(), env, proposal.action)?,
+            _ => Response::new()
+                .add_attribute("action", "vote_finished")
+                .add_attribute("proposal_id", id.to_string()),
+        }
+        .add_event(event);
+
+        // Proposer withdrawing its proposal before anyone voted is not penalized
+        let refund = matches!(outcome, VoteOutcome::Passed | VoteOutcome::Cancelled);
+        Ok(resp.add_messages(settle_deposit(deps.storage, &info.sender, refund)?))
+    }
+
+    fn index_open_vote(
+        storage: &mut dyn Storage,
+        vote: &Addr,
+        expires: &Expiration,
+    ) -> StdResult<()> {
+        match expires {
+            Expiration::AtTime(time) => {
+                OPEN_VOTES_BY_TIME.save(storage, (time.nanos(), vote.clone()), &Empty {})
+            }
+            Expiration::AtHeight(height) => {
+                OPEN_VOTES_BY_HEIGHT.save(storage, (*height, vote.clone()), &Empty {})
+            }
+            Expiration::Never {} => Ok(()),
+        }
+    }
+
+    fn unindex_open_vote(storage: &mut dyn Storage, vote: &Addr, expires: &Expiration) {
+        match expires {
+            Expiration::AtTime(time) => {
+                OPEN_VOTES_BY_TIME.remove(storage, (time.nanos(), vote.clone()))
+            }
+            Expiration::AtHeight(height) => {
+                OPEN_VOTES_BY_HEIGHT.remove(storage, (*height, vote.clone()))
+            }
+            Expiration::Never {} => (),
+        }
+    }
+
+    /// Votes which are neither closed nor expired.
+    fn open_votes(storage: &dyn Storage, block: &BlockInfo) -> StdResult<Vec<Addr>> {
+        let by_time = OPEN_VOTES_BY_TIME
+            .prefix_range(
+                storage,
+                Some(PrefixBound::exclusive(block.time.nanos())),
+                None,
+                Order::Ascending,
+            )
+            .map(|entry| entry.map(|((_, vote), _)| vote));
+        let by_height = OPEN_VOTES_BY_HEIGHT
+            .prefix_range(
+                storage,
+                Some(PrefixBound::exclusive(block.height)),
+                None,
+                Order::Ascending,
+            )
+            .map(|entry| entry.map(|((_, vote), _)| vote));
+        by_time.chain(by_height).collect()
+    }
+
+    /// Id of the proposal decided by the vote, only votes created by this contract have one.
+    fn pending_proposal(storage: &dyn Storage, vote: &Addr) -> Result<u64, ContractError> {
+        PENDING_VOTES
+            .may_load(storage, vote.clone())?
+            .ok_or_else(|| ContractError::NoPendingVote { vote: vote.clone() })
+    }
+
+    /// Records that the proposal passed, its vote stays pending until it is executed.
+    pub fn vote_queued(
+        deps: DepsMut,
+        info: MessageInfo,
+        executable_at: Timestamp,
     ) -> Result<Response, ContractError> {
-        let proposed_admin = PENDING_VOTES.load(deps.storage, info.sender)?;
-        let resp = Response::new().add_attribute("action", "add_member");
+        let id = pending_proposal(deps.storage, &info.sender)?;
+        let proposal = PROPOSALS.update(deps.storage, id, |proposal| -> StdResult<_> {
+            let mut proposal = proposal.ok_or_else(|| StdError::not_found("Proposal"))?;
+            proposal.status = VoteStatus::Queued;
+            proposal.executable_at = Some(executable_at);
+            Ok(proposal)
+        })?;
+        // Ballots don't matter for the queued vote anymore
+        unindex_open_vote(deps.storage, &info.sender, &proposal.expires);
+
+        let resp = Response::new()
+            .add_event(
+                Event::new(events::VOTE_QUEUED)
+                    .add_attribute(attributes::PROPOSAL_ID, id.to_string())
+                    .add_attribute(attributes::VOTE, info.sender.as_str())
+                    .add_attribute(attributes::EXECUTABLE_AT, executable_at.to_string()),
+            )
+            .add_attribute("action", "vote_queued")
+            .add_attribute("proposal_id", id.to_string())
+            .add_attribute("executable_at", executable_at.to_string());
+
+        Ok(resp)
+    }
 
-        if ADMINS.has(deps.storage, proposed_admin.clone()) {
-            return Ok(resp.add_attribute("status", "User already added."));
+    /// Returns the deposit paid for the vote if it should be refunded. Otherwise the deposit is
+    /// burned or kept by the contract, depending on the configuration.
+    fn settle_deposit(
+        storage: &mut dyn Storage,
+        vote: &Addr,
+        refund: bool,
+    ) -> StdResult<Vec<BankMsg>> {
+        let deposit = match DEPOSITS.may_load(storage, vote.clone())? {
+            Some(deposit) => deposit,
+            None => return Ok(vec![]),
+        };
+        DEPOSITS.remove(storage, vote.clone());
+
+        if refund {
+            return Ok(vec![BankMsg::Send {
+                to_address: deposit.depositor.into_string(),
+                amount: vec![deposit.amount],
+            }]);
+        }
+
+        let burn = PROPOSAL_DEPOSIT
+            .may_load(storage)?
+            .map(|config| config.burn_on_failure)
+            .unwrap_or_default();
+        if burn {
+            return Ok(vec![BankMsg::Burn {
+                amount: vec![deposit.amount],
+            }]);
         }
+        Ok(vec![])
+    }
+
+    fn set_proposal_status(
+        storage: &mut dyn Storage,
+        id: u64,
+        status: VoteStatus,
+    ) -> StdResult<Proposal> {
+        PROPOSALS.update(storage, id, |proposal| {
+            let mut proposal = proposal.ok_or_else(|| StdError::not_found("Proposal"))?;
+            proposal.status = status;
+            Ok(proposal)
+        })
+    }
+
+    fn applied_action(action: &ProposalAction) -> &'static str {
+        match action {
+            ProposalAction::AddAdmin { .. } => "apply_add_admin",
+            ProposalAction::RemoveAdmin { .. } => "apply_remove_admin",
+            ProposalAction::UpdateWeight { .. } => "apply_update_weight",
+            ProposalAction::UpdateThreshold { .. } => "apply_update_threshold",
+            ProposalAction::UpdateDonationDenom { .. } => "apply_update_donation_denom",
+            ProposalAction::UpdateDonationAssets { .. } => "apply_update_donation_assets",
+            ProposalAction::UpdateVoteCodeId { .. } => "apply_update_vote_code_id",
+            ProposalAction::UpdateVeto { .. } => "apply_update_veto",
+            ProposalAction::UpdateTimelock { .. } => "apply_update_timelock",
+            ProposalAction::UpdateGuardian { .. } => "apply_update_guardian",
+            ProposalAction::SetPaused { .. } => "apply_set_paused",
+            ProposalAction::UpdateMinAdmins { .. } => "apply_update_min_admins",
+            ProposalAction::UpdateRemainderPolicy { .. } => "apply_update_remainder_policy",
+            ProposalAction::Execute { .. } => "apply_execute",
+        }
+    }
+
+    fn apply_action(
+        deps: DepsMut,
+        env: Env,
+        action: ProposalAction,
+    ) -> Result<Response, ContractError> {
+        let resp = Response::new().add_attribute("action", applied_action(&action));
+
+        match action {
+            ProposalAction::AddAdmin { addr } => {
+                let proposed_admin = deps.api.addr_validate(&addr)?;
+                if ADMINS.has(deps.storage, proposed_admin.clone()) {
+                    return Ok(resp.add_attribute("status", "User already added."));
+                }
+
+                let info = AdminInfo {
+                    joined: env.block.time,
+                    weight: 1,
+                };
+                ADMINS.save(deps.storage, proposed_admin.clone(), &info)?;
+                ADMINS_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+                TOTAL_WEIGHT.update(deps.storage, |weight| -> StdResult<_> { Ok(weight + 1) })?;
+                rewards::register(deps.storage, &proposed_admin)?;
+
+                Ok(resp
+                    .add_event(admin_added(&proposed_admin, info.weight))
+                    .add_attribute("status", format!("Success adding {}.", proposed_admin)))
+            }
+            ProposalAction::RemoveAdmin { addr } => {
+                let admin = deps.api.addr_validate(&addr)?;
+                if !ADMINS.has(deps.storage, admin.clone()) {
+                    return Ok(resp.add_attribute("status", "User already removed."));
+                }
+                if ADMINS_COUNT.load(deps.storage)? <= min_admins(deps.storage)? {
+                    return Ok(resp.add_attribute("status", "Too few admins to remove one."));
+                }
+                let total_weight = total_weight_with(deps.storage, &admin, 0)?;
+                if ensure_reachable(&THRESHOLD.load(deps.storage)?, total_weight).is_err() {
+                    return Ok(resp.add_attribute("status", "Threshold would become unreachable."));
+                }
+
+                let resp = resp
+                    .add_submessages(remove_admin(deps.storage, &env.block, &admin)?)
+                    .add_event(
+                        Event::new(events::ADMIN_REMOVED).add_attribute(attributes::ADMIN, &admin),
+                    );
+
+                Ok(resp.add_attribute("status", format!("Success removing {}.", admin)))
+            }
+            ProposalAction::UpdateWeight { addr, weight } => {
+                let admin = deps.api.addr_validate(&addr)?;
+                let mut info = match ADMINS.may_load(deps.storage, admin.clone())? {
+                    Some(info) => info,
+                    None => return Ok(resp.add_attribute("status", "User is not an admin.")),
+                };
+                let total_weight = total_weight_with(deps.storage, &admin, weight)?;
+                if ensure_reachable(&THRESHOLD.load(deps.storage)?, total_weight).is_err() {
+                    return Ok(resp.add_attribute("status", "Threshold would become unreachable."));
+                }
 
-        ADMINS.save(deps.storage, proposed_admin.clone(), &env.block.time)?;
+                TOTAL_WEIGHT.update(deps.storage, |total| -> StdResult<_> {
+                    Ok(total - info.weight + weight)
+                })?;
+                info.weight = weight;
+                ADMINS.save(deps.storage, admin.clone(), &info)?;
 
-        Ok(resp.add_attribute("status", format!("Success adding {}.", proposed_admin)))
+                Ok(resp.add_attribute("status", format!("Weight of {} set to {}.", admin, weight)))
+            }
+            ProposalAction::UpdateThreshold { threshold } => {
+                if ensure_reachable(&threshold, TOTAL_WEIGHT.load(deps.storage)?).is_err() {
+                    return Ok(resp.add_attribute("status", "Threshold can't be reached."));
+                }
+                THRESHOLD.save(deps.storage, &threshold)?;
+                Ok(resp.add_attribute("status", "Threshold updated."))
+            }
+            ProposalAction::UpdateDonationDenom { denom } => {
+                DONATION_DENOM.save(deps.storage, &denom)?;
+                Ok(resp.add_attribute("status", format!("Donation denom set to {}.", denom)))
+            }
+            ProposalAction::UpdateDonationAssets { assets } => {
+                let assets = validate_assets(deps.api, assets)?;
+                DONATION_ASSETS.save(deps.storage, &assets)?;
+                Ok(resp.add_attribute("status", "Donation assets updated."))
+            }
+            ProposalAction::UpdateVoteCodeId { code_id } => {
+                VOTE_CODE_ID.save(deps.storage, &code_id)?;
+                Ok(resp.add_attribute("status", format!("Vote code id set to {}.", code_id)))
+            }
+            ProposalAction::UpdateVeto { veto } => match veto {
+                Some(veto) => {
+                    let veto = deps.api.addr_validate(&veto)?;
+                    VETO.save(deps.storage, &veto)?;
+                    Ok(resp.add_attribute("status", format!("Veto set to {}.", veto)))
+                }
+                None => {
+                    VETO.remove(deps.storage);
+                    Ok(resp.add_attribute("status", "Veto removed."))
+                }
+            },
+            ProposalAction::UpdateTimelock { timelock } => match timelock {
+                Some(timelock) => {
+                    TIMELOCK.save(deps.storage, &timelock)?;
+                    Ok(resp.add_attribute("status", format!("Timelock set to {}s.", timelock)))
+                }
+                None => {
+                    TIMELOCK.remove(deps.storage);
+                    Ok(resp.add_attribute("status", "Timelock removed."))
+                }
+            },
+            ProposalAction::UpdateGuardian { guardian } => match guardian {
+                Some(guardian) => {
+                    let guardian = deps.api.addr_validate(&guardian)?;
+                    GUARDIAN.save(deps.storage, &guardian)?;
+                    Ok(resp.add_attribute("status", format!("Guardian set to {}.", guardian)))
+                }
+                None => {
+                    GUARDIAN.remove(deps.storage);
+                    Ok(resp.add_attribute("status", "Guardian removed."))
+                }
+            },
+            ProposalAction::SetPaused { paused } => {
+                PAUSED.save(deps.storage, &paused)?;
+                Ok(resp
+                    .add_event(pause_changed(paused))
+                    .add_attribute("status", format!("Paused set to {}.", paused)))
+            }
+            ProposalAction::UpdateMinAdmins { min_admins } => {
+                MIN_ADMINS.save(deps.storage, &min_admins)?;
+                Ok(resp.add_attribute(
+                    "status",
+                    format!("Minimum admins count set to {}.", min_admins),
+                ))
+            }
+            ProposalAction::UpdateRemainderPolicy { policy } => {
+                let policy = validate_remainder_policy(deps.api, policy)?;
+                REMAINDER_POLICY.save(deps.storage, &policy)?;
+                Ok(resp.add_attribute("status", "Remainder policy updated."))
+            }
+            ProposalAction::Execute { msgs } => Ok(resp
+                .add_attribute("status", format!("Dispatching {} messages.", msgs.len()))
+                .add_messages(msgs)),
+        }
     }
 
-    pub fn propose_admin(
+    pub fn propose(
         deps: DepsMut,
+        env: Env,
         info: MessageInfo,
-        addr: String,
+        action: ProposalAction,
         admin_code_id: u64,
+        voting_period: Option<Duration>,
     ) -> Result<Response, ContractError> {
+        if !ADMINS.has(deps.storage, info.sender.clone()) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        if !allowed_while_paused(&action) {
+            ensure_not_paused(deps.storage)?;
+        }
+        validate_action(deps.as_ref(), &action)?;
+
+        match PROPOSAL_DEPOSIT.may_load(deps.storage)? {
+            Some(deposit) => {
+                let denom = DONATION_DENOM.load(deps.storage)?;
+                let paid = cw_utils::must_pay(&info, &denom)?;
+                if paid != deposit.amount {
+                    return Err(ContractError::InvalidDeposit {
+                        expected: coin(deposit.amount.u128(), denom),
+                    });
+                }
+                DEPOSIT_IN_FLIGHT.save(
+                    deps.storage,
+                    &Deposit {
+                        depositor: info.sender.clone(),
+                        amount: coin(paid.u128(), denom),
+                    },
+                )?;
+            }
+            None => cw_utils::nonpayable(&info)?,
+        }
+        PROPOSER_IN_FLIGHT.save(deps.storage, &info.sender)?;
+
+        let voting_period = match voting_period {
+            Some(voting_period) => voting_period,
+            None => VOTING_PERIOD.load(deps.storage)?,
+        };
+
+        let voters = ADMINS
+            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+            .map(|admin| {
+                let (addr, info) = admin?;
+                Ok(Member {
+                    addr: addr.into_string(),
+                    weight: info.weight,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+
+        let threshold = match action {
+            ProposalAction::SetPaused { .. } => Threshold::AbsolutePercentage {
+                percentage: Decimal::from_ratio(2u128, 3u128),
+            },
+            _ => THRESHOLD.load(deps.storage)?,
+        };
+
         let msg = VoteInstantiate {
-            quorum: QUORUM.load(deps.storage)?,
-            proposed_admin: addr,
+            threshold,
+            action,
             admin_code_id,
+            voting_period,
+            voters,
+            proposer: info.sender.to_string(),
+            timelock: TIMELOCK.may_load(deps.storage)?,
         };
 
+        // Admin contract can migrate its votes with an `Execute` proposal
         let msg = WasmMsg::Instantiate {
-            admin: None,
+            admin: Some(env.contract.address.into_string()),
             code_id: VOTE_CODE_ID.load(deps.storage)?,
-            msg: to_binary(&msg)?,
+            msg: to_json_binary(&msg)?,
             funds: vec![],
             label: format!("admin-{}", info.sender),
         };
 
         let resp = Response::new()
             .add_submessage(SubMsg::reply_on_success(msg, VOTE_INSTANTIATE_ID))
-            .add_attribute("action", "propose_admin")
+            .add_attribute("action", "propose")
             .add_attribute("sender", info.sender);
 
         Ok(resp)
     }
 
-    pub fn leave(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
-        ADMINS.remove(deps.storage, info.sender);
+    fn validate_action(deps: Deps, action: &ProposalAction) -> Result<(), ContractError> {
+        match action {
+            ProposalAction::AddAdmin { addr }
+            | ProposalAction::UpdateVeto { veto: Some(addr) }
+            | ProposalAction::UpdateGuardian {
+                guardian: Some(addr),
+            } => {
+                deps.api.addr_validate(addr)?;
+            }
+            ProposalAction::RemoveAdmin { addr } => {
+                let addr = deps.api.addr_validate(addr)?;
+                if !ADMINS.has(deps.storage, addr.clone()) {
+                    return Err(ContractError::NotAdmin { addr });
+                }
+                let min = min_admins(deps.storage)?;
+                if ADMINS_COUNT.load(deps.storage)? <= min {
+                    return Err(ContractError::MinAdmins { min });
+                }
+                let total_weight = total_weight_with(deps.storage, &addr, 0)?;
+                ensure_reachable(&THRESHOLD.load(deps.storage)?, total_weight)?;
+            }
+            ProposalAction::UpdateWeight { addr, weight } => {
+                let addr = deps.api.addr_validate(addr)?;
+                if !ADMINS.has(deps.storage, addr.clone()) {
+                    return Err(ContractError::NotAdmin { addr });
+                }
+                let total_weight = total_weight_with(deps.storage, &addr, *weight)?;
+                ensure_reachable(&THRESHOLD.load(deps.storage)?, total_weight)?;
+            }
+            ProposalAction::UpdateMinAdmins { min_admins } => {
+                if *min_admins == 0 {
+                    return Err(ContractError::ZeroMinAdmins {});
+                }
+            }
+            ProposalAction::UpdateThreshold { threshold } => {
+                validate_threshold(threshold, TOTAL_WEIGHT.load(deps.storage)?)?
+            }
+            ProposalAction::UpdateRemainderPolicy { policy } => {
+                validate_remainder_policy(deps.api, policy.clone())?;
+            }
+            ProposalAction::Execute { msgs } => {
+                if msgs.is_empty() {
+                    return Err(ContractError::NoMessages {});
+                }
+            }
+            ProposalAction::UpdateDonationAssets { assets } => {
+                validate_assets(deps.api, assets.clone())?;
+            }
+            ProposalAction::UpdateDonationDenom { .. }
+            | ProposalAction::UpdateVoteCodeId { .. }
+            | ProposalAction::UpdateVeto { veto: None }
+            | ProposalAction::UpdateTimelock { .. }
+            | ProposalAction::UpdateGuardian { guardian: None }
+            | ProposalAction::SetPaused { .. } => {}
+        }
+        Ok(())
+    }
+
+    /// Checks the threshold is well formed and reachable with the given total weight.
+    pub fn validate_threshold(
+        threshold: &Threshold,
+        total_weight: u64,
+    ) -> Result<(), ContractError> {
+        let validate_percentage = |percentage: Decimal| {
+            if percentage.is_zero() || percentage > Decimal::one() {
+                return Err(ContractError::InvalidPercentage { percentage });
+            }
+            Ok(())
+        };
+
+        match threshold {
+            Threshold::AbsoluteCount { weight } => {
+                if *weight == 0 {
+                    return Err(ContractError::ZeroWeight {});
+                }
+                ensure_reachable(threshold, total_weight)?;
+            }
+            Threshold::AbsolutePercentage { percentage } => validate_percentage(*percentage)?,
+            Threshold::ThresholdQuorum { threshold, quorum } => {
+                if *threshold < Decimal::percent(50) || *threshold > Decimal::one() {
+                    return Err(ContractError::InvalidThreshold {
+                        threshold: *threshold,
+                    });
+                }
+                validate_percentage(*quorum)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Absolute count of weight can become unreachable when the total weight drops, in which
+    /// case every following proposal would be rejected, including the one fixing the threshold.
+    fn ensure_reachable(threshold: &Threshold, total_weight: u64) -> Result<(), ContractError> {
+        match threshold {
+            Threshold::AbsoluteCount { weight } if *weight > total_weight => {
+                Err(ContractError::UnreachableWeight {
+                    weight: *weight,
+                    total_weight,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Total weight after the admin's weight is replaced with the given one.
+    fn total_weight_with(
+        storage: &dyn Storage,
+        admin: &Addr,
+        weight: u64,
+    ) -> Result<u64, ContractError> {
+        let info = ADMINS.load(storage, admin.clone())?;
+        Ok(TOTAL_WEIGHT.load(storage)? - info.weight + weight)
+    }
+
+    pub fn leave(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        if !ADMINS.has(deps.storage, info.sender.clone()) {
+            return Err(ContractError::NotAdmin { addr: info.sender });
+        }
+
+        let min = min_admins(deps.storage)?;
+        if ADMINS_COUNT.load(deps.storage)? <= min {
+            return Err(ContractError::MinAdmins { min });
+        }
+        let total_weight = total_weight_with(deps.storage, &info.sender, 0)?;
+        ensure_reachable(&THRESHOLD.load(deps.storage)?, total_weight)?;
+
+        Ok(Response::new()
+            .add_submessages(remove_admin(deps.storage, &env.block, &info.sender)?)
+            .add_event(
+                Event::new(events::ADMIN_LEFT).add_attribute(attributes::ADMIN, &info.sender),
+            )
+            .add_attribute("action", "leave"))
+    }
+
+    fn min_admins(storage: &dyn Storage) -> StdResult<u64> {
+        Ok(MIN_ADMINS.may_load(storage)?.unwrap_or(1))
+    }
+
+    /// Removes the admin paying out its unclaimed rewards. Votes still open are told to drop its
+    /// ballot, so they are not decided by someone who is no longer an admin. Dropping the ballot
+    /// may pass a vote whose proposal can't be applied right now, f.e. while paused. Such vote
+    /// keeps the ballot instead of failing the removal, see `remove_voter_reply`.
+    fn remove_admin(
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        admin: &Addr,
+    ) -> StdResult<Vec<SubMsg>> {
+        let info = ADMINS.load(storage, admin.clone())?;
+        ADMINS.remove(storage, admin.clone());
+        ADMINS_COUNT.update(storage, |count| -> StdResult<_> { Ok(count - 1) })?;
+        TOTAL_WEIGHT.update(storage, |weight| -> StdResult<_> {
+            Ok(weight - info.weight)
+        })?;
+
+        let rewards = rewards::unregister(storage, admin)?;
+        let mut messages: Vec<_> = rewards::payout(admin, rewards)?
+            .into_iter()
+            .map(SubMsg::new)
+            .collect();
 
-        Ok(Response::new().add_attribute("action", "Leave"))
+        // Expired votes can't be decided by new ballots anymore, so they are skipped
+        for vote in open_votes(storage, block)? {
+            let msg = VoteContract(vote).remove_voter_msg(admin)?;
+            messages.push(SubMsg::reply_on_error(msg, REMOVE_VOTER_ID));
+        }
+        Ok(messages)
+    }
+
+    fn validate_donation_asset(deps: Deps, asset: &AssetInfo) -> Result<(), ContractError> {
+        if let AssetInfo::Native(denom) = asset {
+            if *denom == DONATION_DENOM.load(deps.storage)? {
+                return Ok(());
+            }
+        }
+
+        if !DONATION_ASSETS.load(deps.storage)?.contains(asset) {
+            return Err(ContractError::AssetNotAccepted {
+                asset: rewards::asset_key(asset),
+            });
+        }
+        Ok(())
     }
 
     pub fn donate(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
-        let denom = DONATION_DENOM.load(deps.storage)?;
-        let admins = ADMINS
-            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-            .filter_map(|admin| admin.ok());
+        ensure_not_paused(deps.storage)?;
+        if info.funds.is_empty() {
+            return Err(PaymentError::NoFunds {}.into());
+        }
 
-        let donation = cw_utils::must_pay(&info, &denom)
-            .map_err(|err| StdError::generic_err(err.to_string()))?
-            .u128();
+        ensure_has_admins(deps.storage)?;
 
-        let donation_per_admin = donation / (admins.count() as u128);
+        let recipient = remainder_recipient(deps.storage, &info.sender)?;
+        let mut resp = Response::new().add_attribute("action", "donate");
+        let mut remainders = vec![];
 
-        let admins = ADMINS
-            .keys(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-            .filter_map(|admin| admin.ok());
+        for donation in info.funds {
+            let asset = AssetInfo::Native(donation.denom);
+            validate_donation_asset(deps.as_ref(), &asset)?;
 
-        let messages = admins.into_iter().map(|admin| BankMsg::Send {
-            to_address: admin.to_string(),
-            amount: coins(donation_per_admin, &denom),
-        });
+            let split =
+                rewards::distribute(deps.storage, &asset, donation.amount, recipient.is_none())?;
 
-        let resp = Response::new()
-            .add_messages(messages)
+            resp = resp
+                .add_event(donation_split(
+                    &info.sender,
+                    &asset,
+                    donation.amount,
+                    &split,
+                ))
+                .add_attribute("asset", rewards::asset_key(&asset))
+                .add_attribute("amount", donation.amount.to_string())
+                .add_attribute("per_admin", split.per_admin.to_string());
+
+            if !split.remainder.is_zero() {
+                remainders.push(Asset {
+                    info: asset,
+                    amount: split.remainder,
+                });
+            }
+        }
+
+        if let Some(recipient) = recipient {
+            resp = resp.add_messages(rewards::payout(&recipient, remainders)?);
+        }
+        Ok(resp)
+    }
+
+    fn donation_split(
+        donor: &Addr,
+        asset: &AssetInfo,
+        amount: Uint128,
+        split: &rewards::Split,
+    ) -> Event {
+        Event::new(events::DONATION_SPLIT)
+            .add_attribute(attributes::DONOR, donor)
+            .add_attribute(attributes::ASSET, rewards::asset_key(asset))
+            .add_attribute(attributes::AMOUNT, amount.to_string())
+            .add_attribute(attributes::PER_ADMIN, split.per_admin.to_string())
+            .add_attribute(attributes::REMAINDER, split.remainder.to_string())
+            .add_attribute(attributes::CARRIED, split.carried.to_string())
+    }
+
+    pub fn receive(
+        deps: DepsMut,
+        info: MessageInfo,
+        msg: Cw20ReceiveMsg,
+    ) -> Result<Response, ContractError> {
+        ensure_not_paused(deps.storage)?;
+        let asset = AssetInfo::Cw20(info.sender.into_string());
+        validate_donation_asset(deps.as_ref(), &asset)?;
+
+        match from_json(&msg.msg)? {
+            ReceiveMsg::Donate {} => (),
+        }
+        ensure_has_admins(deps.storage)?;
+
+        let donor = deps.api.addr_validate(&msg.sender)?;
+        let recipient = remainder_recipient(deps.storage, &donor)?;
+        let split = rewards::distribute(deps.storage, &asset, msg.amount, recipient.is_none())?;
+
+        let mut resp = Response::new()
+            .add_event(donation_split(&donor, &asset, msg.amount, &split))
             .add_attribute("action", "donate")
-            .add_attribute("amount", donation.to_string())
-            .add_attribute("per_admin", donation_per_admin.to_string());
+            .add_attribute("sender", donor)
+            .add_attribute("asset", rewards::asset_key(&asset))
+            .add_attribute("amount", msg.amount.to_string())
+            .add_attribute("per_admin", split.per_admin.to_string());
 
+        if let (Some(recipient), false) = (recipient, split.remainder.is_zero()) {
+            let remainder = Asset {
+                info: asset,
+                amount: split.remainder,
+            };
+            resp = resp.add_messages(rewards::payout(&recipient, vec![remainder])?);
+        }
         Ok(resp)
     }
 
-    pub fn vote_instantiate_reply(deps: DepsMut, msg: SubMsgResult) -> StdResult<Response> {
-        let resp = match msg.into_result() {
-            Ok(resp) => resp,
-            Err(err) => return Err(StdError::generic_err(err)),
+    fn ensure_has_admins(storage: &dyn Storage) -> Result<(), ContractError> {
+        if ADMINS_COUNT.load(storage)? == 0 {
+            return Err(ContractError::NoAdmins {});
+        }
+        Ok(())
+    }
+
+    /// Address receiving remainders of donations, `None` if they roll over.
+    fn remainder_recipient(storage: &dyn Storage, donor: &Addr) -> StdResult<Option<Addr>> {
+        let recipient = match REMAINDER_POLICY.may_load(storage)? {
+            None | Some(RemainderPolicy::RollOver) => None,
+            Some(RemainderPolicy::Refund) => Some(donor.clone()),
+            // Validated when the policy was set
+            Some(RemainderPolicy::Treasury { addr }) => Some(Addr::unchecked(addr)),
         };
+        Ok(recipient)
+    }
+
+    pub fn claim_rewards(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+        if !ADMINS.has(deps.storage, info.sender.clone()) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let rewards = rewards::claim(deps.storage, &info.sender)?;
+        if rewards.is_empty() {
+            return Err(ContractError::NoRewards {});
+        }
+
+        let claimed = rewards.iter().map(|reward| {
+            Event::new(events::REWARDS_CLAIMED)
+                .add_attribute(attributes::ADMIN, &info.sender)
+                .add_attribute(attributes::ASSET, rewards::asset_key(&reward.info))
+                .add_attribute(attributes::AMOUNT, reward.amount.to_string())
+        });
+        let resp = Response::new()
+            .add_events(claimed.collect::<Vec<_>>())
+            .add_messages(rewards::payout(&info.sender, rewards)?)
+            .add_attribute("action", "claim_rewards")
+            .add_attribute("admin", info.sender);
+
+        Ok(resp)
+    }
+
+    pub fn clear_vote(deps: DepsMut, vote: String) -> Result<Response, ContractError> {
+        let vote = deps.api.addr_validate(&vote)?;
+        let id = pending_proposal(deps.storage, &vote)?;
+
+        let resp = VoteContract(vote.clone()).status(&deps.querier)?;
+        // Expired vote which reached its threshold reports `Passed` before it is closed, only
+        // `Close {}` on the vote applies the proposal
+        if matches!(
+            resp.status,
+            VoteStatus::Open | VoteStatus::Queued | VoteStatus::Passed
+        ) {
+            return Err(ContractError::VoteOpen { vote });
+        }
+
+        PENDING_VOTES.remove(deps.storage, vote.clone());
+        let proposal = set_proposal_status(deps.storage, id, resp.status)?;
+        unindex_open_vote(deps.storage, &vote, &proposal.expires);
+
+        let resp = Response::new()
+            .add_messages(settle_deposit(deps.storage, &vote, false)?)
+            .add_event(
+                Event::new(events::VOTE_CLEARED)
+                    .add_attribute(attributes::PROPOSAL_ID, id.to_string())
+                    .add_attribute(attributes::VOTE, &vote)
+                    .add_attribute(attributes::STATUS, proposal.status.as_str()),
+            )
+            .add_attribute("action", "clear_vote")
+            .add_attribute("vote", vote);
+
+        Ok(resp)
+    }
+
+    /// Kills the open vote. The vote reports it back as vetoed, so the proposal is closed the
+    /// same way as if it finished on its own.
+    pub fn veto(deps: DepsMut, info: MessageInfo, vote: String) -> Result<Response, ContractError> {
+        if VETO.may_load(deps.storage)? != Some(info.sender.clone()) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let vote = deps.api.addr_validate(&vote)?;
+        let id = pending_proposal(deps.storage, &vote)?;
+
+        // The veto can't block its own removal
+        let action = PROPOSALS.load(deps.storage, id)?.action;
+        if matches!(action, ProposalAction::UpdateVeto { veto: None }) {
+            return Err(ContractError::VetoExempt { vote });
+        }
+
+        let msg = VoteContract(vote.clone()).cancel_msg()?;
+
+        let resp = Response::new()
+            .add_message(msg)
+            .add_attribute("action", "veto")
+            .add_attribute("vote", vote);
+
+        Ok(resp)
+    }
+
+    pub fn set_paused(
+        deps: DepsMut,
+        info: MessageInfo,
+        paused: bool,
+    ) -> Result<Response, ContractError> {
+        if GUARDIAN.may_load(deps.storage)? != Some(info.sender.clone()) {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
 
-        let data = resp
-            .data
-            .ok_or_else(|| StdError::generic_err("No instantiate response data"))?;
+        PAUSED.save(deps.storage, &paused)?;
 
-        let resp = parse_instantiate_response_data(&data)
-            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        let resp = Response::new()
+            .add_event(pause_changed(paused))
+            .add_attribute("action", "set_paused")
+            .add_attribute("paused", paused.to_string());
+
+        Ok(resp)
+    }
+
+    fn pause_changed(paused: bool) -> Event {
+        Event::new(events::PAUSE_CHANGED).add_attribute(attributes::PAUSED, paused.to_string())
+    }
+
+    /// Unpausing and replacing the guardian or veto have to remain possible while paused, so a
+    /// compromised guardian can't keep the contract paused by pausing it again.
+    fn allowed_while_paused(action: &ProposalAction) -> bool {
+        matches!(
+            action,
+            ProposalAction::SetPaused { .. }
+                | ProposalAction::UpdateGuardian { .. }
+                | ProposalAction::UpdateVeto { .. }
+        )
+    }
+
+    pub fn ensure_not_paused(storage: &dyn Storage) -> Result<(), ContractError> {
+        if PAUSED.may_load(storage)?.unwrap_or_default() {
+            return Err(ContractError::Paused {});
+        }
+        Ok(())
+    }
+
+    /// Vote which couldn't drop the ballot of a removed admin keeps counting it.
+    pub fn remove_voter_reply(msg: SubMsgResult) -> Result<Response, ContractError> {
+        let resp = Response::new().add_attribute("action", "remove_voter_reply");
+        match msg {
+            SubMsgResult::Ok(_) => Ok(resp),
+            SubMsgResult::Err(reason) => Ok(resp.add_attribute("failed", reason)),
+        }
+    }
+
+    pub fn vote_instantiate_reply(
+        deps: DepsMut,
+        env: Env,
+        msg: SubMsgResult,
+    ) -> Result<Response, ContractError> {
+        let resp = msg
+            .into_result()
+            .map_err(|reason| ContractError::VoteInstantiate { reason })?;
+
+        let data = resp.data.ok_or(ContractError::MissingReplyData {})?;
+        let resp = parse_instantiate_response_data(&data)?;
         let vote_addr = Addr::unchecked(&resp.contract_address);
 
-        let proposed_admin = PROPOSED_ADMIN.query(&deps.querier, vote_addr.clone())?;
-        PENDING_VOTES.save(deps.storage, vote_addr.clone(), &proposed_admin)?;
+        let id = PROPOSALS_COUNT.may_load(deps.storage)?.unwrap_or_default() + 1;
+        PROPOSALS_COUNT.save(deps.storage, &id)?;
 
-        let resp = Response::new().set_data(to_binary(&ProposeAdminResp { vote_addr })?);
+        let proposer = PROPOSER_IN_FLIGHT.load(deps.storage)?;
+        PROPOSER_IN_FLIGHT.remove(deps.storage);
+        let event = Event::new(events::PROPOSAL_CREATED)
+            .add_attribute(attributes::PROPOSAL_ID, id.to_string())
+            .add_attribute(attributes::PROPOSER, &proposer)
+            .add_attribute(attributes::VOTE, &vote_addr);
+
+        let proposal = Proposal {
+            proposer,
+            action: ACTION.query(&deps.querier, vote_addr.clone())?,
+            vote: vote_addr.clone(),
+            created: env.block.time,
+            expires: EXPIRATION.query(&deps.querier, vote_addr.clone())?,
+            status: VoteStatus::Open,
+            executable_at: None,
+        };
+        index_open_vote(deps.storage, &vote_addr, &proposal.expires)?;
+        PROPOSALS.save(deps.storage, id, &proposal)?;
+        PENDING_VOTES.save(deps.storage, vote_addr.clone(), &id)?;
+        if let Some(deposit) = DEPOSIT_IN_FLIGHT.may_load(deps.storage)? {
+            DEPOSIT_IN_FLIGHT.remove(deps.storage);
+            DEPOSITS.save(deps.storage, vote_addr.clone(), &deposit)?;
+        }
+
+        let resp = Response::new()
+            .add_event(event)
+            .set_data(to_json_binary(&ProposeAdminResp {
+                proposal_id: id,
+                vote_addr,
+            })?);
         Ok(resp)
     }
 }
 
 mod query {
+    use cosmwasm_std::Order;
+    use cw_storage_plus::Bound;
+    use msgs::admin::{
+        AdminEntry, AdminsCountResp, AdminsResp, Asset, ProposalListResp, ProposalResp,
+        TotalPowerResp, VotingPowerResp,
+    };
+    use msgs::client::VoteContract;
+    use msgs::vote::VoteStatus;
+
+    use crate::state::{Proposal, DONATED, PROPOSALS};
+
     use super::*;
 
-    pub fn admins_list(deps: Deps) -> StdResult<AdminsListResp> {
-        let admins: Vec<Addr> = ADMINS
-            .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
-            .filter_map(|admin| admin.ok())
+    pub fn admins_list(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AdminsListResp> {
+        let admins = admins_page(deps, start_after, limit)?
+            .into_iter()
             .map(|(addr, _)| addr)
             .collect();
         let resp = AdminsListResp { admins };
         Ok(resp)
     }
 
+    pub fn admins(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AdminsResp> {
+        let admins = admins_page(deps, start_after, limit)?
+            .into_iter()
+            .map(|(addr, info)| AdminEntry {
+                addr,
+                joined: info.joined,
+                weight: info.weight,
+            })
+            .collect();
+        Ok(AdminsResp { admins })
+    }
+
+    pub fn admins_count(deps: Deps) -> StdResult<AdminsCountResp> {
+        let resp = AdminsCountResp {
+            count: ADMINS_COUNT.load(deps.storage)?,
+        };
+        Ok(resp)
+    }
+
+    fn admins_page(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<(Addr, AdminInfo)>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after
+            .map(|addr| deps.api.addr_validate(&addr))
+            .transpose()?
+            .map(Bound::exclusive);
+
+        ADMINS
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect()
+    }
+
     pub fn join_time(deps: Deps, addr: String) -> StdResult<JoinTimeResp> {
-        let ts = ADMINS.load(deps.storage, deps.api.addr_validate(&addr)?)?;
-        Ok(JoinTimeResp { joined: ts })
+        let info = ADMINS.load(deps.storage, deps.api.addr_validate(&addr)?)?;
+        Ok(JoinTimeResp {
+            joined: info.joined,
+        })
+    }
+
+    pub fn voting_power(deps: Deps, admin: String) -> StdResult<VotingPowerResp> {
+        let weight = ADMINS
+            .may_load(deps.storage, deps.api.addr_validate(&admin)?)?
+            .map(|info| info.weight)
+            .unwrap_or_default();
+        Ok(VotingPowerResp { weight })
+    }
+
+    pub fn total_power(deps: Deps) -> StdResult<TotalPowerResp> {
+        let resp = TotalPowerResp {
+            weight: TOTAL_WEIGHT.load(deps.storage)?,
+        };
+        Ok(resp)
+    }
+
+    pub fn pending_rewards(deps: Deps, admin: String) -> StdResult<PendingRewardsResp> {
+        let admin = deps.api.addr_validate(&admin)?;
+        if !ADMINS.has(deps.storage, admin.clone()) {
+            return Ok(PendingRewardsResp { rewards: vec![] });
+        }
+
+        let resp = PendingRewardsResp {
+            rewards: rewards::pending(deps.storage, &admin)?,
+        };
+        Ok(resp)
+    }
+
+    pub fn donation_assets(deps: Deps) -> StdResult<DonationAssetsResp> {
+        let mut assets = vec![AssetInfo::Native(DONATION_DENOM.load(deps.storage)?)];
+        for asset in DONATION_ASSETS.load(deps.storage)? {
+            if !assets.contains(&asset) {
+                assets.push(asset);
+            }
+        }
+        Ok(DonationAssetsResp { assets })
+    }
+
+    pub fn donations(deps: Deps) -> StdResult<DonationsResp> {
+        let donations = DONATED
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|donated| {
+                let (key, amount) = donated?;
+                Ok(Asset {
+                    info: rewards::asset_from_key(&key)?,
+                    amount,
+                })
+            })
+            .collect::<StdResult<_>>()?;
+        Ok(DonationsResp { donations })
+    }
+
+    pub fn proposal(deps: Deps, env: Env, id: u64) -> StdResult<ProposalResp> {
+        let proposal = PROPOSALS.load(deps.storage, id)?;
+        proposal_resp(deps, &env, id, proposal)
+    }
+
+    pub fn list_proposals(
+        deps: Deps,
+        env: Env,
+        status: Option<VoteStatus>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<ProposalListResp> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(Bound::exclusive);
+
+        let mut proposals = vec![];
+        let mut last_id = None;
+        for proposal in PROPOSALS
+            .range(deps.storage, start, None, Order::Ascending)
+            .take(MAX_SCAN)
+        {
+            let (id, proposal) = proposal?;
+            last_id = Some(id);
+
+            let proposal = proposal_resp(deps, &env, id, proposal)?;
+            if status.is_none() || status.as_ref() == Some(&proposal.status) {
+                proposals.push(proposal);
+                if proposals.len() == limit {
+                    break;
+                }
+            }
+        }
+
+        Ok(ProposalListResp { proposals, last_id })
+    }
+
+    fn proposal_resp(
+        deps: Deps,
+        env: &Env,
+        id: u64,
+        proposal: Proposal,
+    ) -> StdResult<ProposalResp> {
+        // Expired vote is not closed until someone sends it `Close {}`, it knows whether it passed
+        let status = match proposal.status {
+            VoteStatus::Open if proposal.expires.is_expired(&env.block) => {
+                VoteContract(proposal.vote.clone())
+                    .status(&deps.querier)?
+                    .status
+            }
+            status => status,
+        };
+
+        Ok(ProposalResp {
+            id,
+            proposer: proposal.proposer,
+            action: proposal.action,
+            vote: proposal.vote,
+            created: proposal.created,
+            expires: proposal.expires,
+            status,
+            executable_at: proposal.executable_at,
+        })
+    }
+
+    pub fn config(deps: Deps) -> StdResult<ConfigResp> {
+        let resp = ConfigResp {
+            donation_denom: DONATION_DENOM.load(deps.storage)?,
+            vote_code_id: VOTE_CODE_ID.load(deps.storage)?,
+            threshold: THRESHOLD.load(deps.storage)?,
+            voting_period: VOTING_PERIOD.load(deps.storage)?,
+            proposal_deposit: PROPOSAL_DEPOSIT.may_load(deps.storage)?,
+            veto: VETO.may_load(deps.storage)?,
+            timelock: TIMELOCK.may_load(deps.storage)?,
+            guardian: GUARDIAN.may_load(deps.storage)?,
+            paused: PAUSED.may_load(deps.storage)?.unwrap_or_default(),
+            min_admins: MIN_ADMINS.may_load(deps.storage)?.unwrap_or(1),
+            remainder_policy: REMAINDER_POLICY
+                .may_load(deps.storage)?
+                .unwrap_or(RemainderPolicy::RollOver),
+        };
+        Ok(resp)
     }
 }
 
@@ -197,16 +1351,30 @@ mod query {
 mod tests {
     use crate::reply;
 
+    use cosmwasm_std::coins;
     use cosmwasm_std::Addr;
     use cosmwasm_std::Decimal;
+    use cosmwasm_std::Uint128;
     use cw_multi_test::{App, ContractWrapper, Executor};
+    use cw_utils::Duration;
 
     use contract_vote::execute as vote_execute;
     use contract_vote::instantiate as vote_instantiate;
     use contract_vote::query as vote_query;
+    use msgs::admin::{Asset, Member, Threshold};
 
     use super::*;
 
+    fn members(addrs: &[&str]) -> Vec<Member> {
+        addrs
+            .iter()
+            .map(|addr| Member {
+                addr: addr.to_string(),
+                weight: 1,
+            })
+            .collect()
+    }
+
     #[test]
     fn instantiation() {
         let mut app = App::default();
@@ -214,7 +1382,7 @@ mod tests {
         let code = ContractWrapper::new(execute, instantiate, query);
         let code_id = app.store_code(Box::new(code));
 
-        let addr = app
+        let err = app
             .instantiate_contract(
                 code_id,
                 Addr::unchecked("owner"),
@@ -222,30 +1390,78 @@ mod tests {
                     admins: vec![],
                     donation_denom: "eth".to_owned(),
                     vote_code_id: VOTE_INSTANTIATE_ID,
-                    quorum: Decimal::raw(30),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(30),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "Contract",
                 None,
             )
-            .unwrap();
+            .unwrap_err();
 
-        let resp: AdminsListResp = app
-            .wrap()
-            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
-            .unwrap();
+        assert_eq!(ContractError::MinAdmins { min: 1 }, err.downcast().unwrap());
 
-        assert_eq!(resp, AdminsListResp { admins: vec![] });
+        let err = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked("owner"),
+                &InstantiateMsg {
+                    admins: members(&["admin1", "admin2"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id: VOTE_INSTANTIATE_ID,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::zero(),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "Contract",
+                None,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidPercentage {
+                percentage: Decimal::zero()
+            },
+            err.downcast().unwrap()
+        );
 
         let addr = app
             .instantiate_contract(
                 code_id,
                 Addr::unchecked("owner"),
                 &InstantiateMsg {
-                    admins: vec!["admin1".to_owned(), "admin2".to_owned()],
+                    admins: members(&["admin1", "admin2"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id: VOTE_INSTANTIATE_ID,
-                    quorum: Decimal::raw(30),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(30),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "Contract 2",
@@ -255,7 +1471,13 @@ mod tests {
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(addr, &QueryMsg::AdminsList {})
+            .query_wasm_smart(
+                addr,
+                &QueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(
@@ -271,7 +1493,7 @@ mod tests {
         let mut app = App::new(|router, _, storage| {
             router
                 .bank
-                .init_balance(storage, &Addr::unchecked("user"), coins(5, "eth"))
+                .init_balance(storage, &Addr::unchecked("user"), coins(6, "eth"))
                 .unwrap()
         });
 
@@ -283,10 +1505,20 @@ mod tests {
                 code_id,
                 Addr::unchecked("owner"),
                 &InstantiateMsg {
-                    admins: vec!["admin1".to_owned(), "admin2".to_owned()],
+                    admins: members(&["admin1", "admin2"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id: VOTE_INSTANTIATE_ID,
-                    quorum: Decimal::raw(30),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(30),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "Contract",
@@ -308,7 +1540,7 @@ mod tests {
                 .unwrap()
                 .amount
                 .u128(),
-            0
+            1
         );
 
         assert_eq!(
@@ -317,7 +1549,72 @@ mod tests {
                 .unwrap()
                 .amount
                 .u128(),
-            1
+            5
+        );
+
+        let resp: PendingRewardsResp = app
+            .wrap()
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::PendingRewards {
+                    admin: "admin1".to_owned(),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp.rewards,
+            vec![Asset {
+                info: AssetInfo::Native("eth".to_owned()),
+                amount: Uint128::new(2),
+            }]
+        );
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            addr.clone(),
+            &ExecuteMsg::ClaimRewards {},
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                addr.clone(),
+                &ExecuteMsg::ClaimRewards {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::NoRewards {}, err.downcast().unwrap());
+
+        // Remainder of the first donation is split together with the second one
+        app.execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::Donate {},
+            &coins(1, "eth"),
+        )
+        .unwrap();
+
+        for admin in ["admin1", "admin2"] {
+            app.execute_contract(
+                Addr::unchecked(admin),
+                addr.clone(),
+                &ExecuteMsg::ClaimRewards {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            app.wrap()
+                .query_balance(&addr, "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            0
         );
 
         assert_eq!(
@@ -326,7 +1623,7 @@ mod tests {
                 .unwrap()
                 .amount
                 .u128(),
-            2
+            3
         );
 
         assert_eq!(
@@ -335,7 +1632,7 @@ mod tests {
                 .unwrap()
                 .amount
                 .u128(),
-            2
+            3
         );
     }
 
@@ -355,10 +1652,20 @@ mod tests {
                 code_id,
                 Addr::unchecked("owner"),
                 &InstantiateMsg {
-                    admins: vec![],
+                    admins: members(&["owner"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id,
-                    quorum: Decimal::raw(30),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(30),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "Contract",
@@ -368,10 +1675,41 @@ mod tests {
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(addr.clone(), &QueryMsg::AdminsList {})
+            .query_wasm_smart(
+                addr.clone(),
+                &QueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
-        assert_eq!(resp, AdminsListResp { admins: vec![] });
+        assert_eq!(
+            resp,
+            AdminsListResp {
+                admins: vec![Addr::unchecked("owner")],
+            }
+        );
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                addr.clone(),
+                &ExecuteMsg::ProposeAdmin {
+                    addr: String::from("proposed_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: Addr::unchecked("user")
+            },
+            err.downcast().unwrap()
+        );
 
         app.execute_contract(
             Addr::unchecked("owner"),
@@ -379,9 +1717,299 @@ mod tests {
             &ExecuteMsg::ProposeAdmin {
                 addr: String::from("proposed_admin"),
                 admin_code_id,
+                voting_period: None,
             },
             &[],
         )
         .unwrap();
     }
+
+    #[test]
+    fn legacy_migration() {
+        use crate::state::{legacy, PENDING_VOTES};
+        use cosmwasm_std::attr;
+        use cosmwasm_std::testing::{mock_dependencies, mock_env};
+        use msgs::vote::VoteStatus;
+
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        // State as stored by contracts instantiated before versions were tracked
+        for admin in ["owner", "admin1"] {
+            legacy::ADMINS
+                .save(&mut deps.storage, Addr::unchecked(admin), &env.block.time)
+                .unwrap();
+        }
+        DONATION_DENOM
+            .save(&mut deps.storage, &"eth".to_owned())
+            .unwrap();
+        VOTE_CODE_ID.save(&mut deps.storage, &2).unwrap();
+        legacy::QUORUM
+            .save(&mut deps.storage, &Decimal::percent(150))
+            .unwrap();
+        legacy::PENDING_VOTES
+            .save(
+                &mut deps.storage,
+                Addr::unchecked("vote"),
+                &Addr::unchecked("new_admin"),
+            )
+            .unwrap();
+
+        let err = migrate(
+            deps.as_mut(),
+            env.clone(),
+            MigrateMsg {
+                voting_period: None,
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err, ContractError::MissingVotingPeriod {});
+
+        let err = migrate(
+            deps.as_mut(),
+            env.clone(),
+            MigrateMsg {
+                voting_period: Some(Duration::Time(3600)),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err,
+            ContractError::InvalidPercentage {
+                percentage: Decimal::percent(150)
+            }
+        );
+
+        legacy::QUORUM
+            .save(&mut deps.storage, &Decimal::percent(50))
+            .unwrap();
+
+        let resp = migrate(
+            deps.as_mut(),
+            env.clone(),
+            MigrateMsg {
+                voting_period: Some(Duration::Time(3600)),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            resp.attributes,
+            vec![
+                attr("action", "migrate"),
+                attr("cancelled_legacy_vote", "vote")
+            ]
+        );
+
+        let version = cw2::get_contract_version(&deps.storage).unwrap();
+        assert_eq!(version.contract, CONTRACT_NAME);
+        assert_eq!(version.version, CONTRACT_VERSION);
+
+        assert_eq!(ADMINS_COUNT.load(&deps.storage).unwrap(), 2);
+        assert_eq!(TOTAL_WEIGHT.load(&deps.storage).unwrap(), 2);
+        assert_eq!(
+            ADMINS
+                .load(&deps.storage, Addr::unchecked("admin1"))
+                .unwrap(),
+            AdminInfo {
+                joined: env.block.time,
+                weight: 1
+            }
+        );
+        assert!(!PENDING_VOTES.has(&deps.storage, Addr::unchecked("vote")));
+        assert!(!legacy::PENDING_VOTES.has(&deps.storage, Addr::unchecked("vote")));
+
+        let resp = query::proposal(deps.as_ref(), env.clone(), 1).unwrap();
+        assert_eq!(resp.status, VoteStatus::Cancelled);
+        assert_eq!(
+            resp.action,
+            ProposalAction::AddAdmin {
+                addr: "new_admin".to_owned()
+            }
+        );
+        assert_eq!(resp.vote, Addr::unchecked("vote"));
+
+        let resp = query::config(deps.as_ref()).unwrap();
+        assert_eq!(resp.voting_period, Duration::Time(3600));
+        assert_eq!(resp.proposal_deposit, None);
+
+        let resp = query::donation_assets(deps.as_ref()).unwrap();
+        assert_eq!(resp.assets, vec![AssetInfo::Native("eth".to_owned())]);
+
+        // Migrating again only bumps the version
+        migrate(
+            deps.as_mut(),
+            env,
+            MigrateMsg {
+                voting_period: None,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn remainder_policies() {
+        let policies = [
+            (RemainderPolicy::Refund, 2, 0),
+            (
+                RemainderPolicy::Treasury {
+                    addr: "treasury".to_owned(),
+                },
+                1,
+                1,
+            ),
+        ];
+
+        for (policy, user_balance, treasury_balance) in policies {
+            let mut app = App::new(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &Addr::unchecked("user"), coins(6, "eth"))
+                    .unwrap()
+            });
+
+            let code = ContractWrapper::new(execute, instantiate, query);
+            let code_id = app.store_code(Box::new(code));
+
+            let addr = app
+                .instantiate_contract(
+                    code_id,
+                    Addr::unchecked("owner"),
+                    &InstantiateMsg {
+                        admins: members(&["admin1", "admin2"]),
+                        donation_denom: "eth".to_owned(),
+                        vote_code_id: VOTE_INSTANTIATE_ID,
+                        threshold: Threshold::AbsolutePercentage {
+                            percentage: Decimal::percent(50),
+                        },
+                        voting_period: Duration::Time(3600),
+                        proposal_deposit: None,
+                        veto: None,
+                        timelock: None,
+                        guardian: None,
+                        min_admins: None,
+                        remainder_policy: Some(policy),
+                        donation_assets: vec![],
+                    },
+                    &[],
+                    "Contract",
+                    None,
+                )
+                .unwrap();
+
+            app.execute_contract(
+                Addr::unchecked("user"),
+                addr.clone(),
+                &ExecuteMsg::Donate {},
+                &coins(5, "eth"),
+            )
+            .unwrap();
+
+            for (owner, expected) in [
+                ("user", user_balance),
+                ("treasury", treasury_balance),
+                (addr.as_str(), 4),
+            ] {
+                let balance = app.wrap().query_balance(owner, "eth").unwrap();
+                assert_eq!(balance.amount.u128(), expected);
+            }
+
+            let resp: PendingRewardsResp = app
+                .wrap()
+                .query_wasm_smart(
+                    addr,
+                    &QueryMsg::PendingRewards {
+                        admin: "admin1".to_owned(),
+                    },
+                )
+                .unwrap();
+
+            assert_eq!(
+                resp.rewards,
+                vec![Asset {
+                    info: AssetInfo::Native("eth".to_owned()),
+                    amount: Uint128::new(2),
+                }]
+            );
+        }
+    }
+
+    #[test]
+    fn donation_without_admins() {
+        use cosmwasm_std::testing::{mock_dependencies, mock_info};
+
+        let mut deps = mock_dependencies();
+        // Possible only for state migrated from before the minimum admins count was enforced
+        ADMINS_COUNT.save(&mut deps.storage, &0).unwrap();
+
+        let err = exec::donate(deps.as_mut(), mock_info("user", &coins(5, "eth"))).unwrap_err();
+
+        assert_eq!(err, ContractError::NoAdmins {});
+    }
+
+    #[test]
+    fn reply_errors() {
+        use cosmwasm_std::testing::{mock_dependencies, mock_env};
+        use cosmwasm_std::{Reply, SubMsgResponse, SubMsgResult};
+
+        let mut deps = mock_dependencies();
+
+        let err = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: 42,
+                result: SubMsgResult::Err("failed".to_owned()),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::UnknownReplyId { id: 42 });
+
+        let err = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: VOTE_INSTANTIATE_ID,
+                result: SubMsgResult::Err("failed".to_owned()),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::VoteInstantiate {
+                reason: "failed".to_owned()
+            }
+        );
+
+        let err = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: VOTE_INSTANTIATE_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::MissingReplyData {});
+
+        // Failing to drop the ballot of a removed admin doesn't fail the removal
+        let resp = reply(
+            deps.as_mut(),
+            mock_env(),
+            Reply {
+                id: REMOVE_VOTER_ID,
+                result: SubMsgResult::Err("Contract is paused".to_owned()),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            resp.attributes[1],
+            cosmwasm_std::attr("failed", "Contract is paused")
+        );
+    }
 }
@@ -1,5 +1,6 @@
-use cosmwasm_std::{Addr, StdError};
-use cw_utils::PaymentError;
+use cosmwasm_std::{Addr, Coin, Decimal, StdError};
+use cw_utils::{ParseReplyError, PaymentError};
+use msgs::migration::VersionError;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -10,4 +11,48 @@ pub enum ContractError {
     Unauthorized { sender: Addr },
     #[error("Payment error: {0}")]
     Payment(#[from] PaymentError),
+    #[error("Proposal requires a deposit of {expected}")]
+    InvalidDeposit { expected: Coin },
+    #[error("{addr} is not contract admin")]
+    NotAdmin { addr: Addr },
+    #[error("Percentage {percentage} has to be in (0, 1] range")]
+    InvalidPercentage { percentage: Decimal },
+    #[error("Threshold {threshold} has to be in [0.5, 1] range")]
+    InvalidThreshold { threshold: Decimal },
+    #[error("Required weight can't be zero")]
+    ZeroWeight {},
+    #[error("Required weight {weight} exceeds total weight {total_weight}")]
+    UnreachableWeight { weight: u64, total_weight: u64 },
+    #[error("Proposal has no messages to execute")]
+    NoMessages {},
+    #[error("{asset} is not accepted as donation")]
+    AssetNotAccepted { asset: String },
+    #[error("Donations require at least one admin")]
+    NoAdmins {},
+    #[error("No rewards to claim")]
+    NoRewards {},
+    #[error("Vote {vote} is still open")]
+    VoteOpen { vote: Addr },
+    #[error("Contract requires at least {min} admins")]
+    MinAdmins { min: u64 },
+    #[error("Minimum admins count can't be zero")]
+    ZeroMinAdmins {},
+    #[error("Contract is paused")]
+    Paused {},
+    #[error("{vote} is not a pending vote")]
+    NoPendingVote { vote: Addr },
+    #[error("Vote {vote} removes the veto and can't be vetoed")]
+    VetoExempt { vote: Addr },
+    #[error("Unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+    #[error("Vote instantiation failed: {reason}")]
+    VoteInstantiate { reason: String },
+    #[error("Vote instantiation returned no data")]
+    MissingReplyData {},
+    #[error("Invalid vote instantiate response: {0}")]
+    ParseReply(#[from] ParseReplyError),
+    #[error("{0}")]
+    Version(#[from] VersionError),
+    #[error("Voting period is required to migrate legacy state")]
+    MissingVotingPeriod {},
 }
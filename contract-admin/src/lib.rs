@@ -1,11 +1,13 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdError, StdResult};
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response, StdResult};
 use error::ContractError;
-use msgs::admin::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use msgs::admin::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 pub mod contract;
 pub mod error;
+pub mod migration;
+pub mod rewards;
 pub mod state;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -14,7 +16,7 @@ pub fn instantiate(
     env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     contract::instantiate(deps, env, info, msg)
 }
 
@@ -34,9 +36,17 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    contract::migrate(deps, env, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg.id {
-        contract::VOTE_INSTANTIATE_ID => contract::exec::vote_instantiate_reply(deps, msg.result),
-        _ => Err(StdError::generic_err("unknown reply id")),
+        contract::VOTE_INSTANTIATE_ID => {
+            contract::exec::vote_instantiate_reply(deps, env, msg.result)
+        }
+        contract::REMOVE_VOTER_ID => contract::exec::remove_voter_reply(msg.result),
+        id => Err(ContractError::UnknownReplyId { id }),
     }
 }
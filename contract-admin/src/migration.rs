@@ -0,0 +1,73 @@
+use cosmwasm_std::{Addr, Env, Order, StdResult, Storage, Timestamp};
+use cw_utils::Expiration;
+use msgs::admin::{MigrateMsg, ProposalAction, Threshold};
+use msgs::vote::VoteStatus;
+
+use crate::contract::exec;
+use crate::error::ContractError;
+use crate::state::{
+    legacy, AdminInfo, Proposal, ADMINS, ADMINS_COUNT, DONATION_ASSETS, PROPOSALS, PROPOSALS_COUNT,
+    THRESHOLD, TOTAL_WEIGHT, VOTING_PERIOD,
+};
+
+/// Brings state of contracts instantiated before versions were tracked to the current layout.
+/// Returns the legacy votes which were cancelled.
+pub fn from_legacy(
+    storage: &mut dyn Storage,
+    env: &Env,
+    msg: MigrateMsg,
+) -> Result<Vec<Addr>, ContractError> {
+    let voting_period = msg
+        .voting_period
+        .ok_or(ContractError::MissingVotingPeriod {})?;
+    VOTING_PERIOD.save(storage, &voting_period)?;
+
+    // Admins used to store only their join time, every admin gets the same weight
+    let admins: Vec<(Addr, Timestamp)> = legacy::ADMINS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    let admins_count = admins.len() as u64;
+
+    let quorum = legacy::QUORUM.load(storage)?;
+    let threshold = Threshold::AbsolutePercentage { percentage: quorum };
+    exec::validate_threshold(&threshold, admins_count)?;
+    legacy::QUORUM.remove(storage);
+    THRESHOLD.save(storage, &threshold)?;
+    DONATION_ASSETS.save(storage, &vec![])?;
+
+    for (addr, joined) in admins {
+        ADMINS.save(storage, addr, &AdminInfo { joined, weight: 1 })?;
+    }
+    ADMINS_COUNT.save(storage, &admins_count)?;
+    TOTAL_WEIGHT.save(storage, &admins_count)?;
+
+    // Pending votes used to store only the proposed admin. Their proposers and creation times
+    // were not recorded, so the contract itself and the migration time are used instead.
+    // Legacy votes can't be migrated and read admins under the changed layout, so they are only
+    // kept as cancelled proposals. Their `AddMember {}` callbacks no longer parse.
+    let pending: Vec<(Addr, Addr)> = legacy::PENDING_VOTES
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    let mut id = 0;
+    let mut cancelled = Vec::with_capacity(pending.len());
+    for (vote, addr) in pending {
+        legacy::PENDING_VOTES.remove(storage, vote.clone());
+        id += 1;
+        let proposal = Proposal {
+            proposer: env.contract.address.clone(),
+            action: ProposalAction::AddAdmin {
+                addr: addr.into_string(),
+            },
+            vote: vote.clone(),
+            created: env.block.time,
+            expires: Expiration::Never {},
+            status: VoteStatus::Cancelled,
+            executable_at: None,
+        };
+        PROPOSALS.save(storage, id, &proposal)?;
+        cancelled.push(vote);
+    }
+    PROPOSALS_COUNT.save(storage, &id)?;
+    Ok(cancelled)
+}
@@ -0,0 +1,192 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Order, StdError, StdResult, Storage, Uint128,
+    WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use msgs::admin::{Asset, AssetInfo};
+
+use crate::state::{Reward, ADMINS_COUNT, DONATED, REWARDS, REWARD_PER_ADMIN, REWARD_REMAINDER};
+
+// Donations are not sent to admins directly. Instead every asset keeps a growing amount
+// donated per single admin and every admin remembers its value from the last time its rewards
+// were accrued. The difference is what admin earned since then.
+
+/// Storage key of the asset in reward maps.
+pub fn asset_key(info: &AssetInfo) -> String {
+    match info {
+        AssetInfo::Native(denom) => format!("native:{}", denom),
+        AssetInfo::Cw20(addr) => format!("cw20:{}", addr),
+    }
+}
+
+pub fn asset_from_key(key: &str) -> StdResult<AssetInfo> {
+    match key.split_once(':') {
+        Some(("native", denom)) => Ok(AssetInfo::Native(denom.to_owned())),
+        Some(("cw20", addr)) => Ok(AssetInfo::Cw20(addr.to_owned())),
+        _ => Err(StdError::generic_err(format!("Invalid asset key {}", key))),
+    }
+}
+
+/// Amounts a donation was split into.
+pub struct Split {
+    pub per_admin: Uint128,
+    // to be sent elsewhere
+    pub remainder: Uint128,
+    // rolls over to the next donation
+    pub carried: Uint128,
+}
+
+/// Splits `amount` of the asset between current admins. Part which can't be split evenly is
+/// carried over to the next donation if `carry_over` is set, otherwise it is returned to be sent
+/// elsewhere.
+pub fn distribute(
+    storage: &mut dyn Storage,
+    info: &AssetInfo,
+    amount: Uint128,
+    carry_over: bool,
+) -> StdResult<Split> {
+    let key = asset_key(info);
+    DONATED.update(storage, &key, |donated| -> StdResult<_> {
+        Ok(donated.unwrap_or_default() + amount)
+    })?;
+
+    let admins = Uint128::from(ADMINS_COUNT.load(storage)?);
+    let total = amount
+        + REWARD_REMAINDER
+            .may_load(storage, &key)?
+            .unwrap_or_default();
+
+    if admins.is_zero() {
+        REWARD_REMAINDER.save(storage, &key, &total)?;
+        return Ok(Split {
+            per_admin: Uint128::zero(),
+            remainder: Uint128::zero(),
+            carried: total,
+        });
+    }
+
+    let per_admin = total / admins;
+    REWARD_PER_ADMIN.update(storage, &key, |index| -> StdResult<_> {
+        Ok(index.unwrap_or_default() + per_admin)
+    })?;
+
+    // Remainders carried over before the policy changed are returned as well
+    let remainder = total - per_admin * admins;
+    if carry_over {
+        REWARD_REMAINDER.save(storage, &key, &remainder)?;
+        return Ok(Split {
+            per_admin,
+            remainder: Uint128::zero(),
+            carried: remainder,
+        });
+    }
+    REWARD_REMAINDER.remove(storage, &key);
+
+    Ok(Split {
+        per_admin,
+        remainder,
+        carried: Uint128::zero(),
+    })
+}
+
+/// Starts tracking rewards of a new admin, so it is not entitled to past donations.
+pub fn register(storage: &mut dyn Storage, admin: &Addr) -> StdResult<()> {
+    let indexes: Vec<(String, Uint128)> = REWARD_PER_ADMIN
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    for (key, index) in indexes {
+        REWARDS.save(
+            storage,
+            (admin, &key),
+            &Reward {
+                index,
+                pending: Uint128::zero(),
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Rewards earned by the admin and not claimed yet.
+pub fn pending(storage: &dyn Storage, admin: &Addr) -> StdResult<Vec<Asset>> {
+    let mut rewards = vec![];
+    for index in REWARD_PER_ADMIN.range(storage, None, None, Order::Ascending) {
+        let (key, index) = index?;
+        let reward = accrued(storage, admin, &key, index)?;
+        if !reward.pending.is_zero() {
+            rewards.push(Asset {
+                info: asset_from_key(&key)?,
+                amount: reward.pending,
+            });
+        }
+    }
+    Ok(rewards)
+}
+
+/// Resets pending rewards of the admin returning what should be paid out.
+pub fn claim(storage: &mut dyn Storage, admin: &Addr) -> StdResult<Vec<Asset>> {
+    let rewards = pending(storage, admin)?;
+    register(storage, admin)?;
+    Ok(rewards)
+}
+
+/// Stops tracking rewards of the leaving admin returning what should be paid out.
+pub fn unregister(storage: &mut dyn Storage, admin: &Addr) -> StdResult<Vec<Asset>> {
+    let rewards = pending(storage, admin)?;
+    let keys: Vec<String> = REWARD_PER_ADMIN
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    for key in keys {
+        REWARDS.remove(storage, (admin, &key));
+    }
+    Ok(rewards)
+}
+
+/// Messages transferring `rewards` to the admin. All native coins are sent at once.
+pub fn payout(admin: &Addr, rewards: Vec<Asset>) -> StdResult<Vec<CosmosMsg>> {
+    let mut coins = vec![];
+    let mut messages = vec![];
+
+    for reward in rewards {
+        match reward.info {
+            AssetInfo::Native(denom) => coins.push(Coin {
+                denom,
+                amount: reward.amount,
+            }),
+            AssetInfo::Cw20(contract_addr) => messages.push(
+                WasmMsg::Execute {
+                    contract_addr,
+                    msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                        recipient: admin.to_string(),
+                        amount: reward.amount,
+                    })?,
+                    funds: vec![],
+                }
+                .into(),
+            ),
+        }
+    }
+
+    if !coins.is_empty() {
+        messages.insert(
+            0,
+            BankMsg::Send {
+                to_address: admin.to_string(),
+                amount: coins,
+            }
+            .into(),
+        );
+    }
+    Ok(messages)
+}
+
+fn accrued(storage: &dyn Storage, admin: &Addr, key: &str, index: Uint128) -> StdResult<Reward> {
+    // Missing entry means admin joined before the first donation of this asset
+    let mut reward = REWARDS.may_load(storage, (admin, key))?.unwrap_or_default();
+
+    reward.pending += index - reward.index;
+    reward.index = index;
+    Ok(reward)
+}
@@ -1,16 +1,92 @@
-use cosmwasm_std::{Addr, Decimal, Timestamp};
+use cosmwasm_std::{Addr, Coin, Decimal, Empty, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+use msgs::admin::{AssetInfo, ProposalAction, ProposalDeposit, RemainderPolicy, Threshold};
+use msgs::vote::VoteStatus;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-pub const ADMINS: Map<Addr, Timestamp> = Map::new("admins");
+pub use msgs::storage::admin::{AdminInfo, ADMINS};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Deposit {
+    pub depositor: Addr,
+    pub amount: Coin,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Proposal {
+    pub proposer: Addr,
+    pub action: ProposalAction,
+    pub vote: Addr,
+    pub created: Timestamp,
+    pub expires: Expiration,
+    // last status known to the admin contract, expiration is not recorded
+    pub status: VoteStatus,
+    // set once the passed proposal is queued
+    #[serde(default)]
+    pub executable_at: Option<Timestamp>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, Default)]
+pub struct Reward {
+    // donated per admin at the time of the last accrual
+    pub index: Uint128,
+    pub pending: Uint128,
+}
+
+pub const ADMINS_COUNT: Item<u64> = Item::new("admins_count");
+// sum of admins weights
+pub const TOTAL_WEIGHT: Item<u64> = Item::new("total_weight");
 pub const DONATION_DENOM: Item<String> = Item::new("donation_denom");
+// accepted on top of the donation denom
+pub const DONATION_ASSETS: Item<Vec<AssetInfo>> = Item::new("donation_assets");
 pub const VOTE_CODE_ID: Item<u64> = Item::new("vote_code_id");
-// voting contract to proposed admins
-pub const PENDING_VOTES: Map<Addr, Addr> = Map::new("pending_votes");
-pub const QUORUM: Item<Decimal> = Item::new("quorum");
+pub const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+// id of the last created proposal
+pub const PROPOSALS_COUNT: Item<u64> = Item::new("proposals_count");
+// voting contract to id of the proposal it is voting on, removed once the vote finishes
+pub const PENDING_VOTES: Map<Addr, u64> = Map::new("pending_votes");
+// open votes by the time (in nanos) or height they expire at, so expired votes which were never
+// closed are not visited when ballots of a removed admin are dropped
+pub const OPEN_VOTES_BY_TIME: Map<(u64, Addr), Empty> = Map::new("open_votes_by_time");
+pub const OPEN_VOTES_BY_HEIGHT: Map<(u64, Addr), Empty> = Map::new("open_votes_by_height");
+// proposer of the vote being instantiated, used on reply
+pub const PROPOSER_IN_FLIGHT: Item<Addr> = Item::new("proposer_in_flight");
+pub const THRESHOLD: Item<Threshold> = Item::new("threshold");
+pub const VOTING_PERIOD: Item<Duration> = Item::new("voting_period");
+pub const PROPOSAL_DEPOSIT: Item<ProposalDeposit> = Item::new("proposal_deposit");
+// address allowed to kill open votes, there is no veto if not set
+pub const VETO: Item<Addr> = Item::new("veto");
+// seconds passed proposals wait before execution, executed immediately if not set
+pub const TIMELOCK: Item<u64> = Item::new("timelock");
+// address allowed to pause the contract, only proposals can do it if not set
+pub const GUARDIAN: Item<Addr> = Item::new("guardian");
+// not paused if not set
+pub const PAUSED: Item<bool> = Item::new("paused");
+// one if not set
+pub const MIN_ADMINS: Item<u64> = Item::new("min_admins");
+// treasury address is validated before saving, remainders roll over if not set
+pub const REMAINDER_POLICY: Item<RemainderPolicy> = Item::new("remainder_policy");
+// voting contract to deposit paid for it
+pub const DEPOSITS: Map<Addr, Deposit> = Map::new("deposits");
+// deposit of the vote being instantiated, moved to DEPOSITS on reply
+pub const DEPOSIT_IN_FLIGHT: Item<Deposit> = Item::new("deposit_in_flight");
+// Reward maps are keyed by `rewards::asset_key`
+// asset to total amount donated
+pub const DONATED: Map<&str, Uint128> = Map::new("donated");
+// asset to total amount donated per single admin
+pub const REWARD_PER_ADMIN: Map<&str, Uint128> = Map::new("reward_per_admin");
+// asset to donated amount which couldn't be split evenly yet
+pub const REWARD_REMAINDER: Map<&str, Uint128> = Map::new("reward_remainder");
+pub const REWARDS: Map<(&Addr, &str), Reward> = Map::new("rewards");
 
-pub mod vote {
+// State layout of contracts instantiated before versions were tracked
+pub mod legacy {
     use super::*;
 
-    pub const VOTE_OWNER: Item<Addr> = Item::new("vote_owner");
-    pub const PROPOSED_ADMIN: Item<Addr> = Item::new("proposed_admin");
+    pub const ADMINS: Map<Addr, Timestamp> = Map::new("admins");
+    // voting contract to proposed admin
+    pub const PENDING_VOTES: Map<Addr, Addr> = Map::new("pending_votes");
+    pub const QUORUM: Item<Decimal> = Item::new("quorum");
 }
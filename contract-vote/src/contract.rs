@@ -1,141 +1,487 @@
-use crate::state::{PROPOSED_ADMIN, REQUIRED_VOTES, START_TIME, VOTE_OWNER};
+use crate::error::ContractError;
+use crate::state::{
+    Tally, ACTION, EXECUTABLE_AT, EXPIRATION, PROPOSER, STATUS, TALLY, THRESHOLD, TIMELOCK,
+    TOTAL_WEIGHT, VOTERS, VOTE_OWNER,
+};
+use crate::threshold;
 use cosmwasm_std::{
-    to_binary, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
+    to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
 };
+use msgs::migration;
 use msgs::vote::QueryMsg;
 use msgs::{
-    admin::{AdminsListResp, QueryMsg as AdminQueryMsg},
-    vote::{ExecuteMsg, InstantiateMsg, VotesLeftResp},
+    admin::ProposalAction,
+    vote::{ExecuteMsg, InstantiateMsg, MigrateMsg, VoteStatus, VotesLeftResp},
 };
 
+pub const CONTRACT_NAME: &str = "crates.io:contract-vote";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 pub fn instantiate(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
-) -> StdResult<Response> {
-    PROPOSED_ADMIN.save(deps.storage, &deps.api.addr_validate(&msg.proposed_admin)?)?;
-    START_TIME.save(deps.storage, &env.block.time)?;
-    VOTE_OWNER.save(deps.storage, &info.sender)?;
+) -> Result<Response, ContractError> {
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    let vote_owner = &info.sender;
-    let quorum = msg.quorum;
+    match &msg.action {
+        ProposalAction::AddAdmin { addr } | ProposalAction::RemoveAdmin { addr } => {
+            deps.api.addr_validate(addr)?;
+        }
+        _ => (),
+    }
+    ACTION.save(deps.storage, &msg.action)?;
+    EXPIRATION.save(deps.storage, &msg.voting_period.after(&env.block))?;
+    VOTE_OWNER.save(deps.storage, &info.sender)?;
+    PROPOSER.save(deps.storage, &deps.api.addr_validate(&msg.proposer)?)?;
+    if let Some(timelock) = msg.timelock {
+        TIMELOCK.save(deps.storage, &timelock)?;
+    }
 
-    let resp: AdminsListResp = deps
-        .querier
-        .query_wasm_smart(vote_owner, &AdminQueryMsg::AdminsList {})?;
+    // Eligible voters and their weights are fixed for the whole vote, later changes of the
+    // admins set do not affect it.
+    let mut total_weight = 0u64;
+    for voter in msg.voters {
+        let addr = deps.api.addr_validate(&voter.addr)?;
+        if !VOTERS.has(deps.storage, addr.clone()) {
+            VOTERS.save(deps.storage, addr, &voter.weight)?;
+            total_weight += voter.weight;
+        }
+    }
 
-    let admins_decimals = match Decimal::from_atomics(resp.admins.len() as u128, 0) {
-        Ok(val) => val,
-        Err(err) => return Err(StdError::generic_err(err.to_string())),
-    };
+    THRESHOLD.save(deps.storage, &msg.threshold)?;
+    TOTAL_WEIGHT.save(deps.storage, &total_weight)?;
+    TALLY.save(deps.storage, &Tally::default())?;
+    STATUS.save(deps.storage, &VoteStatus::Open)?;
+    Ok(Response::new())
+}
 
-    let required_votes = quorum * admins_decimals;
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // Votes created before versions were tracked had no wasm admin, so they never reach here
+    let previous = cw2::get_contract_version(deps.storage)?;
+    migration::validate_version(&previous, CONTRACT_NAME, CONTRACT_VERSION)?;
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    REQUIRED_VOTES.save(deps.storage, &required_votes)?;
-    Ok(Response::new())
+    Ok(Response::new().add_attribute("action", "migrate"))
 }
 
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::VotesLeft {} => to_binary(&query::votes_left(deps)?),
-        QueryMsg::ProposedAdmin {} => to_binary(&query::proposed_admin(deps)?),
+        QueryMsg::VotesLeft {} => to_json_binary(&query::votes_left(deps)?),
+        QueryMsg::Tally {} => to_json_binary(&query::tally(deps)?),
+        QueryMsg::ProposedAdmin {} => to_json_binary(&query::proposed_admin(deps)?),
+        QueryMsg::Action {} => to_json_binary(&query::action(deps)?),
+        QueryMsg::Status {} => to_json_binary(&query::status(deps, env)?),
+        QueryMsg::Voters {} => to_json_binary(&query::voters(deps)?),
     }
 }
 
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Accept {} => exec::accept(deps, info),
+        ExecuteMsg::Accept {} => exec::accept(deps, env, info),
+        ExecuteMsg::Reject {} => exec::reject(deps, env, info),
+        ExecuteMsg::Abstain {} => exec::abstain(deps, env, info),
+        ExecuteMsg::Retract {} => exec::retract(deps, env, info),
+        ExecuteMsg::RemoveVoter { voter } => exec::remove_voter(deps, env, info, voter),
+        ExecuteMsg::Cancel {} => exec::cancel(deps, env, info),
+        ExecuteMsg::Execute {} => exec::execute(deps, env),
+        ExecuteMsg::Close {} => exec::close(deps, env),
     }
 }
 
 mod query {
-    use crate::state::PROPOSED_ADMIN;
-    use msgs::vote::ProposedAdminResp;
+    use cosmwasm_std::{Addr, Order};
+    use msgs::vote::{
+        ActionResp, ProposedAdminResp, StatusResp, TallyResp, VoteOutcome, VotersResp,
+    };
 
     use super::*;
 
     pub fn votes_left(deps: Deps) -> StdResult<VotesLeftResp> {
         let resp = VotesLeftResp {
-            votes_left: REQUIRED_VOTES.load(deps.storage)?,
+            votes_left: threshold::votes_left(
+                &THRESHOLD.load(deps.storage)?,
+                &TALLY.load(deps.storage)?,
+                TOTAL_WEIGHT.load(deps.storage)?,
+            ),
+        };
+        Ok(resp)
+    }
+
+    pub fn tally(deps: Deps) -> StdResult<TallyResp> {
+        let tally = TALLY.load(deps.storage)?;
+        let resp = TallyResp {
+            threshold: THRESHOLD.load(deps.storage)?,
+            total_weight: TOTAL_WEIGHT.load(deps.storage)?,
+            yes: tally.yes,
+            no: tally.no,
+            abstain: tally.abstain,
         };
         Ok(resp)
     }
 
     pub fn proposed_admin(deps: Deps) -> StdResult<ProposedAdminResp> {
-        let resp = ProposedAdminResp {
-            proposed_admin: PROPOSED_ADMIN.load(deps.storage)?,
+        let proposed_admin = match ACTION.load(deps.storage)? {
+            ProposalAction::AddAdmin { addr } => deps.api.addr_validate(&addr)?,
+            _ => return Err(StdError::generic_err("Proposal is not adding an admin.")),
+        };
+
+        let resp = ProposedAdminResp { proposed_admin };
+        Ok(resp)
+    }
+
+    pub fn action(deps: Deps) -> StdResult<ActionResp> {
+        let resp = ActionResp {
+            action: ACTION.load(deps.storage)?,
+        };
+        Ok(resp)
+    }
+
+    pub fn status(deps: Deps, env: Env) -> StdResult<StatusResp> {
+        let expiration = EXPIRATION.load(deps.storage)?;
+        let status = match STATUS.load(deps.storage)? {
+            VoteStatus::Open if expiration.is_expired(&env.block) => {
+                match exec::expired_outcome(deps.storage)? {
+                    // Closing the vote will queue it
+                    VoteOutcome::Passed if TIMELOCK.may_load(deps.storage)?.is_some() => {
+                        VoteStatus::Queued
+                    }
+                    outcome => outcome.into(),
+                }
+            }
+            status => status,
+        };
+
+        let resp = StatusResp {
+            status,
+            expiration,
+            executable_at: EXECUTABLE_AT.may_load(deps.storage)?,
         };
         Ok(resp)
     }
+
+    pub fn voters(deps: Deps) -> StdResult<VotersResp> {
+        let voters: Vec<Addr> = VOTERS
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<_>>()?;
+        Ok(VotersResp { voters })
+    }
 }
 
 pub mod exec {
 
-    use std::cmp::Ordering;
-
     use cosmwasm_std::{
-        to_binary, Decimal, DepsMut, Empty, MessageInfo, Response, StdError, StdResult, SubMsg,
-        WasmMsg,
+        DepsMut, Env, Event, MessageInfo, Order, Response, StdResult, Storage, SubMsg,
+    };
+    use msgs::client::AdminContract;
+    use msgs::events::{self, attributes};
+    use msgs::vote::{Ballot, VoteOutcome, VoteStatus};
+
+    use crate::error::ContractError;
+    use crate::state::{
+        EXECUTABLE_AT, EXPIRATION, PROPOSER, STATUS, TALLY, THRESHOLD, TIMELOCK, TOTAL_WEIGHT,
+        VOTERS, VOTES, VOTE_OWNER,
     };
-    use msgs::admin::ExecuteMsg;
+    use crate::threshold;
+
+    pub fn accept(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        cast(deps, env, info, Ballot::Accept)
+    }
+
+    pub fn reject(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        cast(deps, env, info, Ballot::Reject)
+    }
+
+    pub fn abstain(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        cast(deps, env, info, Ballot::Abstain)
+    }
 
-    use crate::state::{admin::ADMINS, REQUIRED_VOTES, START_TIME, VOTES, VOTE_OWNER};
+    /// Records the ballot, closing the vote once its outcome can't change anymore. Voter can
+    /// change its ballot as long as the vote is open.
+    fn cast(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        ballot: Ballot,
+    ) -> Result<Response, ContractError> {
+        validate_vote_open(&deps, &env)?;
+        let weight = validate_admin_prove_to_vote(&deps, &info)?;
 
-    pub fn accept(deps: DepsMut, info: MessageInfo) -> StdResult<Response> {
-        if VOTES.has(deps.storage, info.sender.clone()) {
-            return Ok(Response::new());
+        let previous = VOTES.may_load(deps.storage, info.sender.clone())?;
+        if previous.as_ref() == Some(&ballot) {
+            return Err(ContractError::AlreadyVoted { voter: info.sender });
         }
 
-        validate_admin_prove_to_vote(&deps, &info)?;
+        let tally = TALLY.update(deps.storage, |mut tally| -> StdResult<_> {
+            if let Some(previous) = &previous {
+                tally.remove(previous, weight);
+            }
+            tally.add(&ballot, weight);
+            Ok(tally)
+        })?;
+
+        let status = match ballot {
+            Ballot::Accept => "Some admins still need to accept the voting.",
+            _ => "Vote can still pass.",
+        };
+        VOTES.save(deps.storage, info.sender.clone(), &ballot)?;
+
+        let event = match previous {
+            Some(previous) => Event::new(events::BALLOT_CHANGED)
+                .add_attribute(attributes::VOTER, info.sender)
+                .add_attribute(attributes::PREVIOUS, previous.as_str())
+                .add_attribute(attributes::BALLOT, ballot.as_str()),
+            None => Event::new(events::BALLOT_CAST)
+                .add_attribute(attributes::VOTER, info.sender)
+                .add_attribute(attributes::BALLOT, ballot.as_str())
+                .add_attribute(attributes::WEIGHT, weight.to_string()),
+        };
+        let resp = Response::new()
+            .add_event(event)
+            .add_attribute("action", ballot.as_str());
+
+        let threshold = THRESHOLD.load(deps.storage)?;
+        let total_weight = TOTAL_WEIGHT.load(deps.storage)?;
+
+        // Abstaining can also pass the proposal as it lowers the weight which has to accept
+        if threshold::is_passed(&threshold, &tally, total_weight, false) {
+            return Ok(resp.add_submessage(pass(deps, &env)?));
+        }
+
+        if threshold::is_rejected(&threshold, &tally, total_weight) {
+            return Ok(resp
+                .add_submessage(finish(deps, VoteOutcome::Rejected)?)
+                .add_attribute("status", "Vote rejected."));
+        }
+
+        Ok(resp.add_attribute("status", status))
+    }
 
-        REQUIRED_VOTES.update(deps.storage, |votes_left| -> StdResult<Decimal> {
-            Ok(votes_left - Decimal::one())
+    /// Removes the sender's ballot from the tally. It can't close the vote as it only brings
+    /// back weight which may still be cast.
+    pub fn retract(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        validate_vote_open(&deps, &env)?;
+
+        let ballot = VOTES
+            .may_load(deps.storage, info.sender.clone())?
+            .ok_or_else(|| ContractError::NoBallot {
+                voter: info.sender.clone(),
+            })?;
+        let weight = VOTERS.load(deps.storage, info.sender.clone())?;
+
+        TALLY.update(deps.storage, |mut tally| -> StdResult<_> {
+            tally.remove(&ballot, weight);
+            Ok(tally)
         })?;
+        VOTES.remove(deps.storage, info.sender.clone());
+
+        let event = Event::new(events::BALLOT_RETRACTED)
+            .add_attribute(attributes::VOTER, info.sender)
+            .add_attribute(attributes::BALLOT, ballot.as_str());
+        let resp = Response::new()
+            .add_event(event)
+            .add_attribute("action", "retract");
+
+        Ok(resp)
+    }
 
-        VOTES.save(deps.storage, info.sender, &Empty {})?;
+    /// Drops the voter and its ballot. The vote may be decided by the ballots cast so far once
+    /// the voter's weight doesn't count anymore.
+    pub fn remove_voter(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        voter: String,
+    ) -> Result<Response, ContractError> {
+        if info.sender != VOTE_OWNER.load(deps.storage)? {
+            return Err(ContractError::Unauthorized {
+                sender: info.sender,
+            });
+        }
+
+        let resp = Response::new().add_attribute("action", "remove_voter");
 
-        if REQUIRED_VOTES.load(deps.storage)? >= Decimal::one() {
-            return Ok(Response::new()
-                .add_attribute("action", "accept")
-                .add_attribute("status", "Some admins still need to accept the voting."));
+        // Outcome of the closed vote is not affected anymore
+        if validate_vote_open(&deps, &env).is_err() {
+            return Ok(resp.add_attribute("status", "Vote is closed."));
         }
 
-        let msg = WasmMsg::Execute {
-            contract_addr: VOTE_OWNER.load(deps.storage)?.into_string(),
-            msg: to_binary(&ExecuteMsg::AddMember {})?,
-            funds: vec![],
+        let voter = deps.api.addr_validate(&voter)?;
+        let weight = match VOTERS.may_load(deps.storage, voter.clone())? {
+            Some(weight) => weight,
+            None => return Ok(resp.add_attribute("status", "Not a voter.")),
+        };
+        VOTERS.remove(deps.storage, voter.clone());
+        let total_weight =
+            TOTAL_WEIGHT.update(deps.storage, |total| -> StdResult<_> { Ok(total - weight) })?;
+
+        let mut tally = TALLY.load(deps.storage)?;
+        if let Some(ballot) = VOTES.may_load(deps.storage, voter.clone())? {
+            tally.remove(&ballot, weight);
+            TALLY.save(deps.storage, &tally)?;
+            VOTES.remove(deps.storage, voter.clone());
+        }
+
+        let resp = resp.add_attribute("voter", voter);
+        let threshold = THRESHOLD.load(deps.storage)?;
+
+        if threshold::is_passed(&threshold, &tally, total_weight, false) {
+            return Ok(resp.add_submessage(pass(deps, &env)?));
+        }
+
+        if threshold::is_rejected(&threshold, &tally, total_weight) {
+            return Ok(resp
+                .add_submessage(finish(deps, VoteOutcome::Rejected)?)
+                .add_attribute("status", "Vote rejected."));
+        }
+
+        Ok(resp)
+    }
+
+    /// Withdraws the proposal. Sent by the admin contract it is a veto, which can kill the vote
+    /// at any point, while the proposer can only cancel it before anyone voted.
+    pub fn cancel(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+        let outcome = if info.sender == VOTE_OWNER.load(deps.storage)? {
+            // Veto can also stop the queued proposal before it is executed
+            if STATUS.load(deps.storage)? != VoteStatus::Queued {
+                validate_vote_open(&deps, &env)?;
+            }
+            VoteOutcome::Vetoed
+        } else if info.sender == PROPOSER.load(deps.storage)? {
+            validate_vote_open(&deps, &env)?;
+            let voted = VOTES
+                .keys(deps.storage, None, None, Order::Ascending)
+                .next()
+                .is_some();
+            if voted {
+                return Err(ContractError::BallotsCast {});
+            }
+            VoteOutcome::Cancelled
+        } else {
+            return Err(ContractError::NotProposer {
+                sender: info.sender,
+            });
         };
 
         let resp = Response::new()
-            .add_submessage(SubMsg::new(msg))
-            .add_attribute("action", "accept");
+            .add_submessage(finish(deps, outcome)?)
+            .add_attribute("action", "cancel")
+            .add_attribute("sender", info.sender);
 
         Ok(resp)
     }
 
-    fn validate_admin_prove_to_vote(deps: &DepsMut, info: &MessageInfo) -> StdResult<()> {
-        let admin_start_time = match ADMINS.query(
-            &deps.querier,
-            VOTE_OWNER.load(deps.storage)?,
-            info.sender.clone(),
-        )? {
-            Some(v) => v,
-            None => return Err(StdError::generic_err("Non admin accept!.")),
+    /// Applies the queued proposal once its timelock passed.
+    pub fn execute(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+        if STATUS.load(deps.storage)? != VoteStatus::Queued {
+            return Err(ContractError::NotQueued {});
+        }
+        let executable_at = EXECUTABLE_AT.load(deps.storage)?;
+        if env.block.time < executable_at {
+            return Err(ContractError::Timelocked { executable_at });
+        }
+
+        let resp = Response::new()
+            .add_submessage(finish(deps, VoteOutcome::Passed)?)
+            .add_attribute("action", "execute");
+
+        Ok(resp)
+    }
+
+    pub fn close(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+        if STATUS.load(deps.storage)? != VoteStatus::Open {
+            return Err(ContractError::VoteClosed {});
+        }
+        if !EXPIRATION.load(deps.storage)?.is_expired(&env.block) {
+            return Err(ContractError::VotingPeriodNotEnded {});
+        }
+
+        let msg = match expired_outcome(deps.storage)? {
+            VoteOutcome::Passed => pass(deps, &env)?,
+            outcome => finish(deps, outcome)?,
+        };
+        let resp = Response::new()
+            .add_submessage(msg)
+            .add_attribute("action", "close");
+
+        Ok(resp)
+    }
+
+    /// Outcome of the vote once its voting period ended. Votes with a quorum can still pass
+    /// if enough of the cast votes accepted the proposal.
+    pub fn expired_outcome(storage: &dyn Storage) -> StdResult<VoteOutcome> {
+        let passed = threshold::is_passed(
+            &THRESHOLD.load(storage)?,
+            &TALLY.load(storage)?,
+            TOTAL_WEIGHT.load(storage)?,
+            true,
+        );
+
+        if passed {
+            Ok(VoteOutcome::Passed)
+        } else {
+            Ok(VoteOutcome::Expired)
+        }
+    }
+
+    /// Passes the vote. With a timelock the admin contract is only told the proposal is queued,
+    /// it is applied once `Execute {}` is sent after the delay.
+    fn pass(deps: DepsMut, env: &Env) -> StdResult<SubMsg> {
+        let timelock = match TIMELOCK.may_load(deps.storage)? {
+            Some(timelock) => timelock,
+            None => return finish(deps, VoteOutcome::Passed),
         };
 
-        let vote_start_time = START_TIME.load(deps.storage)?;
+        let executable_at = env.block.time.plus_seconds(timelock);
+        STATUS.save(deps.storage, &VoteStatus::Queued)?;
+        EXECUTABLE_AT.save(deps.storage, &executable_at)?;
+
+        let owner = AdminContract(VOTE_OWNER.load(deps.storage)?);
+        Ok(SubMsg::new(owner.vote_queued_msg(executable_at)?))
+    }
+
+    /// Closes the vote reporting its outcome to the admin contract.
+    fn finish(deps: DepsMut, outcome: VoteOutcome) -> StdResult<SubMsg> {
+        STATUS.save(deps.storage, &outcome.clone().into())?;
+
+        let owner = AdminContract(VOTE_OWNER.load(deps.storage)?);
+        Ok(SubMsg::new(owner.vote_finished_msg(outcome)?))
+    }
 
-        if admin_start_time.cmp(&vote_start_time) == Ordering::Greater {
-            return Err(StdError::generic_err(
-                "Admin is not allowed to vote due to being approved after vote is created.",
-            ))?;
+    fn validate_vote_open(deps: &DepsMut, env: &Env) -> Result<(), ContractError> {
+        if STATUS.load(deps.storage)? != VoteStatus::Open {
+            return Err(ContractError::VoteClosed {});
+        }
+        if EXPIRATION.load(deps.storage)?.is_expired(&env.block) {
+            return Err(ContractError::Expired {});
         }
         Ok(())
     }
+
+    /// Returns weight of the sender's ballot.
+    fn validate_admin_prove_to_vote(
+        deps: &DepsMut,
+        info: &MessageInfo,
+    ) -> Result<u64, ContractError> {
+        if let Some(weight) = VOTERS.may_load(deps.storage, info.sender.clone())? {
+            return Ok(weight);
+        }
+
+        // Not part of the snapshot - ask the admin contract only to report a meaningful error
+        let owner = AdminContract(VOTE_OWNER.load(deps.storage)?);
+        match owner.join_time(&deps.querier, &info.sender) {
+            Ok(_) => Err(ContractError::JoinedAfterVoteStart {
+                addr: info.sender.clone(),
+            }),
+            Err(_) => Err(ContractError::NotAdmin {
+                addr: info.sender.clone(),
+            }),
+        }
+    }
 }
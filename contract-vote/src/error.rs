@@ -0,0 +1,35 @@
+use cosmwasm_std::{Addr, StdError, Timestamp};
+use msgs::migration::VersionError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    StdError(#[from] StdError),
+    #[error("{sender} is not the admin contract owning the vote")]
+    Unauthorized { sender: Addr },
+    #[error("{addr} is not contract admin")]
+    NotAdmin { addr: Addr },
+    #[error("{addr} was approved as admin after the vote started")]
+    JoinedAfterVoteStart { addr: Addr },
+    #[error("{voter} already cast the same ballot")]
+    AlreadyVoted { voter: Addr },
+    #[error("{voter} has no ballot to retract")]
+    NoBallot { voter: Addr },
+    #[error("Vote is already closed")]
+    VoteClosed {},
+    #[error("Vote has expired")]
+    Expired {},
+    #[error("Voting period has not ended yet")]
+    VotingPeriodNotEnded {},
+    #[error("{sender} is not the proposer of the vote")]
+    NotProposer { sender: Addr },
+    #[error("Vote can't be cancelled once ballots are cast")]
+    BallotsCast {},
+    #[error("Vote is not queued")]
+    NotQueued {},
+    #[error("Vote can't be executed before {executable_at}")]
+    Timelocked { executable_at: Timestamp },
+    #[error("{0}")]
+    Version(#[from] VersionError),
+}
@@ -1,16 +1,51 @@
-use cosmwasm_std::{Addr, Decimal, Empty, Timestamp};
+use cosmwasm_std::{Addr, Timestamp};
 use cw_storage_plus::{Item, Map};
+use msgs::admin::Threshold;
+use msgs::vote::{Ballot, VoteStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 
-// with Item<Vec<T>> you need to load, modify, save -> this is gas costly
-// Use map instead so that it will just save new vote without loading
-// Every vote is accepting.
-pub const VOTES: Map<Addr, Empty> = Map::new("votes");
-pub const REQUIRED_VOTES: Item<Decimal> = Item::new("required_approvals");
-pub const PROPOSED_ADMIN: Item<Addr> = Item::new("proposed_admin");
-pub const VOTE_OWNER: Item<Addr> = Item::new("vote_owner");
-pub const START_TIME: Item<Timestamp> = Item::new("start_time");
+// Read by the admin contract with raw queries
+pub use msgs::storage::vote::{ACTION, EXPIRATION, VOTE_OWNER};
+
+// Weight of cast ballots
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema, Default)]
+pub struct Tally {
+    pub yes: u64,
+    pub no: u64,
+    pub abstain: u64,
+}
 
-pub mod admin {
-    use super::*;
-    pub const ADMINS: Map<Addr, Timestamp> = Map::new("admins");
+impl Tally {
+    pub fn add(&mut self, ballot: &Ballot, weight: u64) {
+        match ballot {
+            Ballot::Accept => self.yes += weight,
+            Ballot::Reject => self.no += weight,
+            Ballot::Abstain => self.abstain += weight,
+        }
+    }
+
+    pub fn remove(&mut self, ballot: &Ballot, weight: u64) {
+        match ballot {
+            Ballot::Accept => self.yes -= weight,
+            Ballot::Reject => self.no -= weight,
+            Ballot::Abstain => self.abstain -= weight,
+        }
+    }
 }
+
+// with Item<Vec<T>> you need to load, modify, save -> this is gas costly
+// Use map instead so that it will just save new vote without loading
+pub const VOTES: Map<Addr, Ballot> = Map::new("votes");
+// admins allowed to vote with their weights, snapshotted when the vote is created
+pub const VOTERS: Map<Addr, u64> = Map::new("voters");
+pub const THRESHOLD: Item<Threshold> = Item::new("threshold");
+// weight of all voters
+pub const TOTAL_WEIGHT: Item<u64> = Item::new("total_weight");
+pub const TALLY: Item<Tally> = Item::new("tally");
+pub const STATUS: Item<VoteStatus> = Item::new("status");
+pub const PROPOSER: Item<Addr> = Item::new("proposer");
+// seconds the passed proposal waits before execution, executed immediately if not set
+pub const TIMELOCK: Item<u64> = Item::new("timelock");
+// set once the proposal is queued
+pub const EXECUTABLE_AT: Item<Timestamp> = Item::new("executable_at");
@@ -0,0 +1,59 @@
+use cosmwasm_std::{Decimal, Uint128};
+use msgs::admin::Threshold;
+
+use crate::state::Tally;
+
+// Percentages are applied to the weight scaled by this factor, so rounding happens only once
+const PRECISION_FACTOR: u128 = 1_000_000_000;
+
+/// Weight needed to reach `percentage` of `weight`, rounded up.
+pub fn votes_needed(weight: u64, percentage: Decimal) -> u64 {
+    let applied = Uint128::new(PRECISION_FACTOR * weight as u128) * percentage;
+    applied.u128().div_ceil(PRECISION_FACTOR) as u64
+}
+
+/// Checks if the proposal passed. Until the vote `expired` all missing votes are assumed to be
+/// rejecting, afterwards only cast votes are taken into account.
+pub fn is_passed(threshold: &Threshold, tally: &Tally, total_weight: u64, expired: bool) -> bool {
+    let (yes_needed, quorum_needed) = required(threshold, tally, total_weight, expired);
+    tally.yes >= yes_needed && tally.yes + tally.no + tally.abstain >= quorum_needed
+}
+
+/// Checks if the proposal can't pass even if every remaining voter accepts it.
+pub fn is_rejected(threshold: &Threshold, tally: &Tally, total_weight: u64) -> bool {
+    let (yes_needed, _) = required(threshold, tally, total_weight, false);
+    let remaining = total_weight - tally.yes - tally.no - tally.abstain;
+    tally.yes + remaining < yes_needed
+}
+
+/// Accepting votes still needed for the proposal to pass.
+pub fn votes_left(threshold: &Threshold, tally: &Tally, total_weight: u64) -> u64 {
+    let (yes_needed, quorum_needed) = required(threshold, tally, total_weight, false);
+    let cast = tally.yes + tally.no + tally.abstain;
+    yes_needed
+        .saturating_sub(tally.yes)
+        .max(quorum_needed.saturating_sub(cast))
+}
+
+/// Accepting weight and total cast weight needed to pass. Proposal never passes without any
+/// accepting vote.
+fn required(threshold: &Threshold, tally: &Tally, total_weight: u64, expired: bool) -> (u64, u64) {
+    let (yes_needed, quorum_needed) = match threshold {
+        Threshold::AbsoluteCount { weight } => (*weight, 0),
+        Threshold::AbsolutePercentage { percentage } => {
+            (votes_needed(total_weight, *percentage), 0)
+        }
+        Threshold::ThresholdQuorum { threshold, quorum } => {
+            let opinions = if expired {
+                tally.yes + tally.no
+            } else {
+                total_weight - tally.abstain
+            };
+            (
+                votes_needed(opinions, *threshold),
+                votes_needed(total_weight, *quorum),
+            )
+        }
+    };
+    (yes_needed.max(1), quorum_needed)
+}
@@ -0,0 +1,290 @@
+//! Typed wrappers around contract addresses, building messages and queries for them so callers
+//! don't need to serialize `ExecuteMsg`/`QueryMsg` by hand.
+
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CosmosMsg, QuerierWrapper, StdResult, Timestamp, WasmMsg,
+};
+use cw_utils::Duration;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::admin::{
+    AdminsCountResp, AdminsListResp, AdminsResp, ConfigResp, DonationAssetsResp, DonationsResp,
+    ExecuteMsg as AdminExecuteMsg, JoinTimeResp, PendingRewardsResp, ProposalAction,
+    ProposalListResp, ProposalResp, QueryMsg as AdminQueryMsg, TotalPowerResp, VotingPowerResp,
+};
+use crate::storage::admin::{AdminInfo, ADMINS};
+use crate::storage::vote::VOTE_OWNER;
+use crate::vote::{
+    ActionResp, ExecuteMsg as VoteExecuteMsg, ProposedAdminResp, QueryMsg as VoteQueryMsg,
+    StatusResp, TallyResp, VoteOutcome, VoteStatus, VotersResp, VotesLeftResp,
+};
+
+fn execute_msg<T: Serialize>(addr: &Addr, msg: &T, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: addr.to_string(),
+        msg: to_json_binary(msg)?,
+        funds,
+    }
+    .into())
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AdminContract(pub Addr);
+
+impl AdminContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call(&self, msg: AdminExecuteMsg) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, vec![])
+    }
+
+    fn query<T: DeserializeOwned>(
+        &self,
+        querier: &QuerierWrapper,
+        msg: &AdminQueryMsg,
+    ) -> StdResult<T> {
+        querier.query_wasm_smart(&self.0, msg)
+    }
+
+    pub fn vote_finished_msg(&self, outcome: VoteOutcome) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::VoteFinished { outcome })
+    }
+
+    pub fn vote_queued_msg(&self, executable_at: Timestamp) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::VoteQueued { executable_at })
+    }
+
+    pub fn propose_admin_msg(
+        &self,
+        addr: impl Into<String>,
+        admin_code_id: u64,
+        voting_period: Option<Duration>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::ProposeAdmin {
+            addr: addr.into(),
+            admin_code_id,
+            voting_period,
+        })
+    }
+
+    /// Proposal deposit, if required, has to be passed in `funds`.
+    pub fn propose_msg(
+        &self,
+        action: ProposalAction,
+        admin_code_id: u64,
+        voting_period: Option<Duration>,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        let msg = AdminExecuteMsg::Propose {
+            action,
+            admin_code_id,
+            voting_period,
+        };
+        execute_msg(&self.0, &msg, funds)
+    }
+
+    pub fn leave_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::Leave {})
+    }
+
+    pub fn donate_msg(&self, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &AdminExecuteMsg::Donate {}, funds)
+    }
+
+    pub fn claim_rewards_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::ClaimRewards {})
+    }
+
+    pub fn clear_vote_msg(&self, vote: impl Into<String>) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::ClearVote { vote: vote.into() })
+    }
+
+    pub fn veto_msg(&self, vote: impl Into<String>) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::Veto { vote: vote.into() })
+    }
+
+    pub fn set_paused_msg(&self, paused: bool) -> StdResult<CosmosMsg> {
+        self.call(AdminExecuteMsg::SetPaused { paused })
+    }
+
+    pub fn admins_list(
+        &self,
+        querier: &QuerierWrapper,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AdminsListResp> {
+        self.query(querier, &AdminQueryMsg::AdminsList { start_after, limit })
+    }
+
+    pub fn admins(
+        &self,
+        querier: &QuerierWrapper,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AdminsResp> {
+        self.query(querier, &AdminQueryMsg::Admins { start_after, limit })
+    }
+
+    pub fn admins_count(&self, querier: &QuerierWrapper) -> StdResult<AdminsCountResp> {
+        self.query(querier, &AdminQueryMsg::AdminsCount {})
+    }
+
+    pub fn join_time(
+        &self,
+        querier: &QuerierWrapper,
+        admin: impl Into<String>,
+    ) -> StdResult<JoinTimeResp> {
+        let admin = admin.into();
+        self.query(querier, &AdminQueryMsg::JoinTime { admin })
+    }
+
+    pub fn voting_power(
+        &self,
+        querier: &QuerierWrapper,
+        admin: impl Into<String>,
+    ) -> StdResult<VotingPowerResp> {
+        let admin = admin.into();
+        self.query(querier, &AdminQueryMsg::VotingPower { admin })
+    }
+
+    pub fn total_power(&self, querier: &QuerierWrapper) -> StdResult<TotalPowerResp> {
+        self.query(querier, &AdminQueryMsg::TotalPower {})
+    }
+
+    pub fn config(&self, querier: &QuerierWrapper) -> StdResult<ConfigResp> {
+        self.query(querier, &AdminQueryMsg::Config {})
+    }
+
+    pub fn pending_rewards(
+        &self,
+        querier: &QuerierWrapper,
+        admin: impl Into<String>,
+    ) -> StdResult<PendingRewardsResp> {
+        let admin = admin.into();
+        self.query(querier, &AdminQueryMsg::PendingRewards { admin })
+    }
+
+    pub fn donation_assets(&self, querier: &QuerierWrapper) -> StdResult<DonationAssetsResp> {
+        self.query(querier, &AdminQueryMsg::DonationAssets {})
+    }
+
+    pub fn donations(&self, querier: &QuerierWrapper) -> StdResult<DonationsResp> {
+        self.query(querier, &AdminQueryMsg::Donations {})
+    }
+
+    /// Reads the admin straight from the contract storage.
+    pub fn admin_info(
+        &self,
+        querier: &QuerierWrapper,
+        admin: &Addr,
+    ) -> StdResult<Option<AdminInfo>> {
+        ADMINS.query(querier, self.0.clone(), admin.clone())
+    }
+
+    pub fn list_proposals(
+        &self,
+        querier: &QuerierWrapper,
+        status: Option<VoteStatus>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<ProposalListResp> {
+        let msg = AdminQueryMsg::ListProposals {
+            status,
+            start_after,
+            limit,
+        };
+        self.query(querier, &msg)
+    }
+
+    pub fn proposal(&self, querier: &QuerierWrapper, id: u64) -> StdResult<ProposalResp> {
+        self.query(querier, &AdminQueryMsg::Proposal { id })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct VoteContract(pub Addr);
+
+impl VoteContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call(&self, msg: VoteExecuteMsg) -> StdResult<CosmosMsg> {
+        execute_msg(&self.0, &msg, vec![])
+    }
+
+    fn query<T: DeserializeOwned>(
+        &self,
+        querier: &QuerierWrapper,
+        msg: &VoteQueryMsg,
+    ) -> StdResult<T> {
+        querier.query_wasm_smart(&self.0, msg)
+    }
+
+    pub fn accept_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(VoteExecuteMsg::Accept {})
+    }
+
+    pub fn reject_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(VoteExecuteMsg::Reject {})
+    }
+
+    pub fn abstain_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(VoteExecuteMsg::Abstain {})
+    }
+
+    pub fn retract_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(VoteExecuteMsg::Retract {})
+    }
+
+    pub fn remove_voter_msg(&self, voter: impl Into<String>) -> StdResult<CosmosMsg> {
+        self.call(VoteExecuteMsg::RemoveVoter {
+            voter: voter.into(),
+        })
+    }
+
+    pub fn cancel_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(VoteExecuteMsg::Cancel {})
+    }
+
+    pub fn execute_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(VoteExecuteMsg::Execute {})
+    }
+
+    pub fn close_msg(&self) -> StdResult<CosmosMsg> {
+        self.call(VoteExecuteMsg::Close {})
+    }
+
+    /// Admin contract which created the vote, read straight from the contract storage.
+    pub fn owner(&self, querier: &QuerierWrapper) -> StdResult<Addr> {
+        VOTE_OWNER.query(querier, self.0.clone())
+    }
+
+    pub fn votes_left(&self, querier: &QuerierWrapper) -> StdResult<VotesLeftResp> {
+        self.query(querier, &VoteQueryMsg::VotesLeft {})
+    }
+
+    pub fn tally(&self, querier: &QuerierWrapper) -> StdResult<TallyResp> {
+        self.query(querier, &VoteQueryMsg::Tally {})
+    }
+
+    pub fn proposed_admin(&self, querier: &QuerierWrapper) -> StdResult<ProposedAdminResp> {
+        self.query(querier, &VoteQueryMsg::ProposedAdmin {})
+    }
+
+    pub fn action(&self, querier: &QuerierWrapper) -> StdResult<ActionResp> {
+        self.query(querier, &VoteQueryMsg::Action {})
+    }
+
+    pub fn status(&self, querier: &QuerierWrapper) -> StdResult<StatusResp> {
+        self.query(querier, &VoteQueryMsg::Status {})
+    }
+
+    pub fn voters(&self, querier: &QuerierWrapper) -> StdResult<VotersResp> {
+        self.query(querier, &VoteQueryMsg::Voters {})
+    }
+}
@@ -1,37 +1,164 @@
+use cosmwasm_schema::QueryResponses;
 use cosmwasm_std::Addr;
+use cw_utils::{Duration, Expiration};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+pub mod client;
+pub mod migration;
+pub mod storage;
+
 pub mod vote {
-    use cosmwasm_std::Decimal;
+    use cosmwasm_std::Timestamp;
 
+    use super::admin::{Member, ProposalAction, Threshold};
     use super::*;
 
-    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
     #[serde(rename_all = "snake_case")]
     pub struct InstantiateMsg {
-        pub quorum: Decimal,
-        pub proposed_admin: String,
+        pub threshold: Threshold,
+        pub action: ProposalAction,
         pub admin_code_id: u64,
+        pub voting_period: Duration,
+        // Admins allowed to vote on the proposal
+        pub voters: Vec<Member>,
+        // Admin who created the proposal, allowed to cancel it
+        pub proposer: String,
+        // Seconds between the proposal passing and its execution
+        pub timelock: Option<u64>,
     }
 
     #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
     #[serde(rename_all = "snake_case")]
     pub enum ExecuteMsg {
         Accept {},
+        Reject {},
+        Abstain {},
+        // Withdraws the sender's ballot while the vote is still open
+        Retract {},
+        // Sent by the admin contract once the voter stops being an admin, its ballot is dropped
+        // and the vote is settled against the remaining weight
+        RemoveVoter { voter: String },
+        // Withdraws the proposal. The proposer can cancel it until any ballot is cast, the admin
+        // contract at any time on behalf of its veto.
+        Cancel {},
+        // Applies the queued proposal once its timelock passed, anyone can trigger it
+        Execute {},
+        // Closes the vote once its voting period ended, anyone can trigger it
+        Close {},
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct MigrateMsg {}
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Ballot {
+        Accept,
+        Reject,
+        Abstain,
+    }
+
+    impl Ballot {
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                Ballot::Accept => "accept",
+                Ballot::Reject => "reject",
+                Ballot::Abstain => "abstain",
+            }
+        }
     }
 
     #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
     #[serde(rename_all = "snake_case")]
+    pub enum VoteStatus {
+        Open,
+        // Passed, waiting for the timelock before it can be executed.
+        Queued,
+        Passed,
+        // Not enough admins are left to reach the threshold.
+        Rejected,
+        // Voting period ended before the threshold was reached.
+        Expired,
+        // Withdrawn by the proposer.
+        Cancelled,
+        // Killed by the veto of the admin contract.
+        Vetoed,
+    }
+
+    impl VoteStatus {
+        pub fn as_str(&self) -> &'static str {
+            match self {
+                VoteStatus::Open => "open",
+                VoteStatus::Queued => "queued",
+                VoteStatus::Passed => "passed",
+                VoteStatus::Rejected => "rejected",
+                VoteStatus::Expired => "expired",
+                VoteStatus::Cancelled => "cancelled",
+                VoteStatus::Vetoed => "vetoed",
+            }
+        }
+    }
+
+    // Final result of the vote reported to the admin contract
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum VoteOutcome {
+        Passed,
+        Rejected,
+        Expired,
+        Cancelled,
+        Vetoed,
+    }
+
+    impl From<VoteOutcome> for VoteStatus {
+        fn from(outcome: VoteOutcome) -> Self {
+            match outcome {
+                VoteOutcome::Passed => VoteStatus::Passed,
+                VoteOutcome::Rejected => VoteStatus::Rejected,
+                VoteOutcome::Expired => VoteStatus::Expired,
+                VoteOutcome::Cancelled => VoteStatus::Cancelled,
+                VoteOutcome::Vetoed => VoteStatus::Vetoed,
+            }
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
+    #[serde(rename_all = "snake_case")]
     pub enum QueryMsg {
+        // Accepting votes still needed for the proposal to pass
+        #[returns(VotesLeftResp)]
         VotesLeft {},
+        #[returns(TallyResp)]
+        Tally {},
+        // Fails if the proposal is not adding an admin
+        #[returns(ProposedAdminResp)]
         ProposedAdmin {},
+        #[returns(ActionResp)]
+        Action {},
+        #[returns(StatusResp)]
+        Status {},
+        #[returns(VotersResp)]
+        Voters {},
     }
 
     #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
     #[serde(rename_all = "snake_case")]
     pub struct VotesLeftResp {
-        pub votes_left: Decimal,
+        pub votes_left: u64,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct TallyResp {
+        pub threshold: Threshold,
+        // Weight of all eligible voters
+        pub total_weight: u64,
+        pub yes: u64,
+        pub no: u64,
+        pub abstain: u64,
     }
 
     #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
@@ -39,39 +166,245 @@ pub mod vote {
     pub struct ProposedAdminResp {
         pub proposed_admin: Addr,
     }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct VotersResp {
+        pub voters: Vec<Addr>,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct ActionResp {
+        pub action: ProposalAction,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct StatusResp {
+        pub status: VoteStatus,
+        pub expiration: Expiration,
+        // Set once the proposal is queued
+        pub executable_at: Option<Timestamp>,
+    }
 }
 
 pub mod admin {
-    use cosmwasm_std::{Decimal, Timestamp};
+    use cosmwasm_std::{CosmosMsg, Decimal, Timestamp, Uint128};
+    use cw20::Cw20ReceiveMsg;
 
+    use super::vote::{VoteOutcome, VoteStatus};
     use super::*;
 
-    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
     #[serde(rename_all = "snake_case")]
     pub struct InstantiateMsg {
-        pub admins: Vec<String>,
+        pub admins: Vec<Member>,
         pub donation_denom: String,
         pub vote_code_id: u64,
-        pub quorum: Decimal,
+        pub threshold: Threshold,
+        // Default voting period for proposals which don't specify their own
+        pub voting_period: Duration,
+        pub proposal_deposit: Option<ProposalDeposit>,
+        // Address allowed to kill any open vote
+        pub veto: Option<String>,
+        // Seconds passed proposals wait before they are executed, giving time to veto them
+        pub timelock: Option<u64>,
+        // Address allowed to pause and unpause the contract
+        pub guardian: Option<String>,
+        // Admins can't leave or be removed below this count, one if not set
+        pub min_admins: Option<u64>,
+        // Remainders roll over to the next donation if not set
+        pub remainder_policy: Option<RemainderPolicy>,
+        // Assets accepted as donations besides the donation denom
+        pub donation_assets: Vec<AssetInfo>,
+    }
+
+    // Admin with its voting weight
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct Member {
+        pub addr: String,
+        pub weight: u64,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct MigrateMsg {
+        // Required when migrating state created before versions were tracked
+        pub voting_period: Option<Duration>,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum AssetInfo {
+        Native(String),
+        Cw20(String),
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct Asset {
+        pub info: AssetInfo,
+        pub amount: Uint128,
+    }
+
+    // What happens with the part of a donation which can't be split evenly between admins
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum RemainderPolicy {
+        // Returned to the donor
+        Refund,
+        // Split together with the next donation of the same asset
+        RollOver,
+        Treasury { addr: String },
+    }
+
+    // Required votes for a proposal to pass. Percentages are applied to the voters weight and
+    // rounded up, so f.e. 40% of 3 admins requires 2 accepting votes.
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum Threshold {
+        // Fixed weight of accepting votes
+        AbsoluteCount { weight: u64 },
+        // Part of the total weight which has to accept
+        AbsolutePercentage { percentage: Decimal },
+        // Part of the total weight which has to vote (`quorum`) and part of the non abstaining
+        // votes which has to accept (`threshold`). Until the vote expires all missing votes are
+        // assumed to be rejecting.
+        ThresholdQuorum { threshold: Decimal, quorum: Decimal },
     }
 
+    // Paid in donation denom by the admin opening a vote. Refunded if the vote passes.
     #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
     #[serde(rename_all = "snake_case")]
+    pub struct ProposalDeposit {
+        pub amount: Uint128,
+        // Deposits of failed proposals are kept by the contract unless this is set
+        pub burn_on_failure: bool,
+    }
+
+    // Change applied by the admin contract once the vote on it passes
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ProposalAction {
+        // New admins have weight of one
+        AddAdmin { addr: String },
+        RemoveAdmin { addr: String },
+        UpdateWeight { addr: String, weight: u64 },
+        UpdateThreshold { threshold: Threshold },
+        UpdateDonationDenom { denom: String },
+        UpdateDonationAssets { assets: Vec<AssetInfo> },
+        UpdateVoteCodeId { code_id: u64 },
+        // Removes the veto role if `None`
+        UpdateVeto { veto: Option<String> },
+        // Removes the timelock if `None`
+        UpdateTimelock { timelock: Option<u64> },
+        // Removes the guardian if `None`
+        UpdateGuardian { guardian: Option<String> },
+        // Requires a super-majority of two thirds of the total weight, regardless of the
+        // configured threshold. It is the only proposal allowed while paused.
+        SetPaused { paused: bool },
+        UpdateMinAdmins { min_admins: u64 },
+        UpdateRemainderPolicy { policy: RemainderPolicy },
+        // Messages dispatched from the admin contract account, f.e. treasury transfers
+        Execute { msgs: Vec<CosmosMsg> },
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
     pub enum ExecuteMsg {
-        AddMember {},
-        ProposeAdmin { addr: String, admin_code_id: u64 },
-        // How admins know that there is a voting ongoing and they need to send Accept message
-        // Blockchain does not inform users about that. This is purely done on f.e. discord.
-        // I believe you can also watch messages on blockchain which can give you a hint about that.
+        // Sent by the vote contract once it is closed, applies the action if the proposal passed
+        VoteFinished {
+            outcome: VoteOutcome,
+        },
+        // Sent by the vote contract once its proposal passed but waits for the timelock
+        VoteQueued {
+            executable_at: Timestamp,
+        },
+        ProposeAdmin {
+            addr: String,
+            admin_code_id: u64,
+            voting_period: Option<Duration>,
+        },
+        Propose {
+            action: ProposalAction,
+            admin_code_id: u64,
+            voting_period: Option<Duration>,
+        },
         Leave {},
+        // Donation is split between admins, who need to claim their part
         Donate {},
+        // Cw20 donations, sent by the token contract
+        Receive(Cw20ReceiveMsg),
+        ClaimRewards {},
+        // Removes the pending vote once it is closed, for votes which did not report their outcome
+        ClearVote {
+            vote: String,
+        },
+        // Kills an open vote, only the configured veto can send it. Proposals removing the veto
+        // can't be vetoed.
+        Veto {
+            vote: String,
+        },
+        // Paused contract rejects donations, new proposals and applying passed proposals other
+        // than `SetPaused`, `UpdateGuardian` and `UpdateVeto`, only the configured guardian can
+        // send it
+        SetPaused {
+            paused: bool,
+        },
     }
 
-    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema, QueryResponses)]
     #[serde(rename_all = "snake_case")]
     pub enum QueryMsg {
-        AdminsList {},
+        #[returns(AdminsListResp)]
+        AdminsList {
+            start_after: Option<String>,
+            limit: Option<u32>,
+        },
+        // Admins together with the time they joined
+        #[returns(AdminsResp)]
+        Admins {
+            start_after: Option<String>,
+            limit: Option<u32>,
+        },
+        #[returns(AdminsCountResp)]
+        AdminsCount {},
+        #[returns(JoinTimeResp)]
         JoinTime { admin: String },
+        // Zero for non admins
+        #[returns(VotingPowerResp)]
+        VotingPower { admin: String },
+        #[returns(TotalPowerResp)]
+        TotalPower {},
+        #[returns(ConfigResp)]
+        Config {},
+        #[returns(PendingRewardsResp)]
+        PendingRewards { admin: String },
+        #[returns(DonationAssetsResp)]
+        DonationAssets {},
+        // Total amounts ever donated per asset
+        #[returns(DonationsResp)]
+        Donations {},
+        // Proposals ordered by id, f.e. open ones which admins still need to vote on. Filtering by
+        // status inspects a bounded number of proposals, so a page may be short even if more
+        // proposals match, the next page starts after `last_id`
+        #[returns(ProposalListResp)]
+        ListProposals {
+            status: Option<VoteStatus>,
+            start_after: Option<u64>,
+            limit: Option<u32>,
+        },
+        #[returns(ProposalResp)]
+        Proposal { id: u64 },
+    }
+
+    // Message expected in the `Cw20ReceiveMsg::msg`
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ReceiveMsg {
+        Donate {},
     }
 
     #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
@@ -86,9 +419,142 @@ pub mod admin {
         pub admins: Vec<Addr>,
     }
 
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct AdminEntry {
+        pub addr: Addr,
+        pub joined: Timestamp,
+        pub weight: u64,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct AdminsResp {
+        pub admins: Vec<AdminEntry>,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct AdminsCountResp {
+        pub count: u64,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct VotingPowerResp {
+        pub weight: u64,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct TotalPowerResp {
+        pub weight: u64,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct ConfigResp {
+        pub donation_denom: String,
+        pub vote_code_id: u64,
+        pub threshold: Threshold,
+        pub voting_period: Duration,
+        pub proposal_deposit: Option<ProposalDeposit>,
+        pub veto: Option<Addr>,
+        pub timelock: Option<u64>,
+        pub guardian: Option<Addr>,
+        pub paused: bool,
+        pub min_admins: u64,
+        pub remainder_policy: RemainderPolicy,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct PendingRewardsResp {
+        pub rewards: Vec<Asset>,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct DonationAssetsResp {
+        pub assets: Vec<AssetInfo>,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct DonationsResp {
+        pub donations: Vec<Asset>,
+    }
+
     #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, JsonSchema)]
     #[serde(rename_all = "snake_case")]
     pub struct ProposeAdminResp {
+        pub proposal_id: u64,
         pub vote_addr: Addr,
     }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct ProposalResp {
+        pub id: u64,
+        pub proposer: Addr,
+        pub action: ProposalAction,
+        pub vote: Addr,
+        pub created: Timestamp,
+        pub expires: Expiration,
+        pub status: VoteStatus,
+        pub executable_at: Option<Timestamp>,
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub struct ProposalListResp {
+        pub proposals: Vec<ProposalResp>,
+        // last inspected proposal, `None` if there were none left
+        pub last_id: Option<u64>,
+    }
+}
+
+/// Types and attribute keys of the events emitted by the contracts. The wasm module prefixes
+/// event types with `wasm-`, so `ADMIN_ADDED` is observed on chain as `wasm-admin_added`.
+pub mod events {
+    pub const ADMIN_ADDED: &str = "admin_added";
+    pub const ADMIN_LEFT: &str = "admin_left";
+    pub const ADMIN_REMOVED: &str = "admin_removed";
+    pub const PROPOSAL_CREATED: &str = "proposal_created";
+    pub const BALLOT_CAST: &str = "ballot_cast";
+    pub const BALLOT_CHANGED: &str = "ballot_changed";
+    pub const BALLOT_RETRACTED: &str = "ballot_retracted";
+    pub const VOTE_PASSED: &str = "vote_passed";
+    pub const VOTE_REJECTED: &str = "vote_rejected";
+    pub const VOTE_EXPIRED: &str = "vote_expired";
+    pub const VOTE_CANCELLED: &str = "vote_cancelled";
+    pub const VOTE_VETOED: &str = "vote_vetoed";
+    pub const VOTE_QUEUED: &str = "vote_queued";
+    // vote closed by the admin contract without being told its outcome
+    pub const VOTE_CLEARED: &str = "vote_cleared";
+    pub const PAUSE_CHANGED: &str = "pause_changed";
+    pub const DONATION_SPLIT: &str = "donation_split";
+    pub const REWARDS_CLAIMED: &str = "rewards_claimed";
+
+    pub mod attributes {
+        pub const ADMIN: &str = "admin";
+        pub const WEIGHT: &str = "weight";
+        pub const PROPOSAL_ID: &str = "proposal_id";
+        pub const PROPOSER: &str = "proposer";
+        pub const VOTE: &str = "vote";
+        pub const STATUS: &str = "status";
+        pub const EXECUTABLE_AT: &str = "executable_at";
+        pub const PAUSED: &str = "paused";
+        pub const VOTER: &str = "voter";
+        pub const BALLOT: &str = "ballot";
+        pub const PREVIOUS: &str = "previous";
+        pub const DONOR: &str = "donor";
+        pub const ASSET: &str = "asset";
+        pub const AMOUNT: &str = "amount";
+        pub const PER_ADMIN: &str = "per_admin";
+        // part of the donation which couldn't be split and was sent away
+        pub const REMAINDER: &str = "remainder";
+        // part of the donation which couldn't be split and rolls over to the next donation
+        pub const CARRIED: &str = "carried";
+    }
 }
@@ -0,0 +1,41 @@
+//! Version checks shared by the contracts' migrate entry points.
+
+use cw2::ContractVersion;
+use semver::Version;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum VersionError {
+    #[error("Cannot migrate from {contract} contract")]
+    InvalidContract { contract: String },
+    #[error("Cannot migrate from newer version {version}")]
+    CannotMigrateVersion { version: String },
+    #[error("Invalid contract version {version}")]
+    InvalidVersion { version: String },
+}
+
+/// Ensures the stored contract can be upgraded to the given contract name and version.
+pub fn validate_version(
+    previous: &ContractVersion,
+    name: &str,
+    version: &str,
+) -> Result<(), VersionError> {
+    if previous.contract != name {
+        return Err(VersionError::InvalidContract {
+            contract: previous.contract.clone(),
+        });
+    }
+
+    if parse_version(&previous.version)? > parse_version(version)? {
+        return Err(VersionError::CannotMigrateVersion {
+            version: previous.version.clone(),
+        });
+    }
+    Ok(())
+}
+
+fn parse_version(version: &str) -> Result<Version, VersionError> {
+    version.parse().map_err(|_| VersionError::InvalidVersion {
+        version: version.to_owned(),
+    })
+}
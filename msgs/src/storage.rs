@@ -0,0 +1,36 @@
+//! Storage layouts read by the other contract with raw queries. The owning contract declares its
+//! state from here too, so renaming a key or changing a stored type affects both sides at once.
+
+pub mod vote {
+    use cosmwasm_std::Addr;
+    use cw_storage_plus::Item;
+    use cw_utils::Expiration;
+
+    use crate::admin::ProposalAction;
+
+    pub const VOTE_OWNER_KEY: &str = "vote_owner";
+    pub const ACTION_KEY: &str = "action";
+    pub const EXPIRATION_KEY: &str = "expiration";
+
+    // admin contract which created the vote
+    pub const VOTE_OWNER: Item<Addr> = Item::new(VOTE_OWNER_KEY);
+    pub const ACTION: Item<ProposalAction> = Item::new(ACTION_KEY);
+    pub const EXPIRATION: Item<Expiration> = Item::new(EXPIRATION_KEY);
+}
+
+pub mod admin {
+    use cosmwasm_std::{Addr, Timestamp};
+    use cw_storage_plus::Map;
+    use schemars::JsonSchema;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+    pub struct AdminInfo {
+        pub joined: Timestamp,
+        pub weight: u64,
+    }
+
+    pub const ADMINS_KEY: &str = "admins";
+
+    pub const ADMINS: Map<Addr, AdminInfo> = Map::new(ADMINS_KEY);
+}
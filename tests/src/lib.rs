@@ -1,26 +1,55 @@
 #[cfg(test)]
 mod tests {
 
-    use std::str::FromStr;
-
-    use cosmwasm_std::{coins, from_binary, Addr, Decimal, Empty, StdError};
+    use cosmwasm_schema::{schema_for, QueryResponses};
+    use cosmwasm_std::testing::MockStorage;
+    use cosmwasm_std::{
+        coin, coins, from_json, to_json_binary, Addr, BankMsg, Decimal, Empty, Event, Timestamp,
+        Uint128,
+    };
+    use cw2::query_contract_info;
+    use cw20::Cw20ReceiveMsg;
     use cw_multi_test::{next_block, App, AppResponse, Contract, ContractWrapper, Executor};
+    use cw_utils::{Duration, Expiration, PaymentError};
     use msgs::admin::{
-        AdminsListResp, ExecuteMsg as AdminExecuteMsg, InstantiateMsg as AdminInstantiateMsg,
-        ProposeAdminResp, QueryMsg as AdminQueryMsg,
+        AdminEntry, AdminsCountResp, AdminsListResp, AdminsResp, Asset, AssetInfo, ConfigResp,
+        DonationAssetsResp, DonationsResp, ExecuteMsg as AdminExecuteMsg,
+        InstantiateMsg as AdminInstantiateMsg, Member, MigrateMsg as AdminMigrateMsg,
+        PendingRewardsResp, ProposalAction, ProposalDeposit, ProposalListResp, ProposalResp,
+        ProposeAdminResp, QueryMsg as AdminQueryMsg, ReceiveMsg, RemainderPolicy, Threshold,
+        TotalPowerResp, VotingPowerResp,
     };
+    use msgs::client::{AdminContract, VoteContract};
+    use msgs::events::{self, attributes};
+    use msgs::migration::VersionError;
+    use msgs::storage;
     use msgs::vote::{
-        ExecuteMsg as VoteExecuteMsg, ProposedAdminResp, QueryMsg as VoteQueryMsg, VotesLeftResp,
+        ActionResp, ExecuteMsg as VoteExecuteMsg, MigrateMsg as VoteMigrateMsg, ProposedAdminResp,
+        QueryMsg as VoteQueryMsg, StatusResp, TallyResp, VoteOutcome, VoteStatus, VotersResp,
+        VotesLeftResp,
     };
 
+    use contract_admin::error::ContractError;
     use contract_admin::{
-        execute as admin_execute, instantiate as admin_instantiate, query as admin_query,
-        reply as admin_reply,
+        execute as admin_execute, instantiate as admin_instantiate, migrate as admin_migrate,
+        query as admin_query, reply as admin_reply,
     };
+    use contract_vote::error::ContractError as VoteError;
     use contract_vote::{
-        execute as vote_execute, instantiate as vote_instantiate, query as vote_query,
+        execute as vote_execute, instantiate as vote_instantiate, migrate as vote_migrate,
+        query as vote_query,
     };
 
+    fn members(addrs: &[&str]) -> Vec<Member> {
+        addrs
+            .iter()
+            .map(|addr| Member {
+                addr: addr.to_string(),
+                weight: 1,
+            })
+            .collect()
+    }
+
     fn admin() -> Box<dyn Contract<Empty>> {
         let contract = ContractWrapper::new(admin_execute, admin_instantiate, admin_query)
             .with_reply(admin_reply);
@@ -48,15 +77,20 @@ mod tests {
                 admin_code_id,
                 Addr::unchecked("owner"),
                 &AdminInstantiateMsg {
-                    admins: vec![
-                        String::from("owner"),
-                        String::from("admin1"),
-                        String::from("admin2"),
-                        String::from("admin3"),
-                    ],
+                    admins: members(&["owner", "admin1", "admin2", "admin3"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id,
-                    quorum: Decimal::percent(75),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(75),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "vote",
@@ -71,12 +105,13 @@ mod tests {
                 &AdminExecuteMsg::ProposeAdmin {
                     addr: String::from("new_admin"),
                     admin_code_id,
+                    voting_period: None,
                 },
                 &[],
             )
             .unwrap();
 
-        let propose_admin_resp: ProposeAdminResp = from_binary(&resp.data.unwrap()).unwrap();
+        let propose_admin_resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
 
         let resp: VotesLeftResp = app
             .wrap()
@@ -86,12 +121,7 @@ mod tests {
             )
             .unwrap();
 
-        assert_eq!(
-            resp,
-            VotesLeftResp {
-                votes_left: Decimal::from_str("3.0").unwrap()
-            }
-        );
+        assert_eq!(resp, VotesLeftResp { votes_left: 3 });
 
         app.execute_contract(
             Addr::unchecked("admin1"),
@@ -109,21 +139,24 @@ mod tests {
             )
             .unwrap();
 
+        assert_eq!(resp, VotesLeftResp { votes_left: 2 });
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                propose_admin_resp.vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
         assert_eq!(
-            resp,
-            VotesLeftResp {
-                votes_left: Decimal::from_str("2.0").unwrap()
-            }
+            VoteError::AlreadyVoted {
+                voter: Addr::unchecked("admin1")
+            },
+            err.downcast().unwrap()
         );
 
-        app.execute_contract(
-            Addr::unchecked("admin1"),
-            propose_admin_resp.vote_addr.clone(),
-            &VoteExecuteMsg::Accept {},
-            &[],
-        )
-        .unwrap();
-
         let resp: VotesLeftResp = app
             .wrap()
             .query_wasm_smart(
@@ -132,12 +165,7 @@ mod tests {
             )
             .unwrap();
 
-        assert_eq!(
-            resp,
-            VotesLeftResp {
-                votes_left: Decimal::from_str("2.0").unwrap()
-            }
-        );
+        assert_eq!(resp, VotesLeftResp { votes_left: 2 });
 
         app.execute_contract(
             Addr::unchecked("admin2"),
@@ -155,12 +183,7 @@ mod tests {
             )
             .unwrap();
 
-        assert_eq!(
-            resp,
-            VotesLeftResp {
-                votes_left: Decimal::from_str("1.0").unwrap()
-            }
-        );
+        assert_eq!(resp, VotesLeftResp { votes_left: 1 });
 
         app.execute_contract(
             Addr::unchecked("admin3"),
@@ -178,32 +201,26 @@ mod tests {
             )
             .unwrap();
 
-        assert_eq!(
-            resp,
-            VotesLeftResp {
-                votes_left: Decimal::zero()
-            }
-        );
+        assert_eq!(resp, VotesLeftResp { votes_left: 0 });
 
-        app.execute_contract(
-            Addr::unchecked("admin3"),
-            propose_admin_resp.vote_addr.clone(),
-            &VoteExecuteMsg::Accept {},
-            &[],
-        )
-        .unwrap();
+        // Re-sent ballot is checked against the closed vote first
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin3"),
+                propose_admin_resp.vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(VoteError::VoteClosed {}, err.downcast().unwrap());
 
         let resp: VotesLeftResp = app
             .wrap()
             .query_wasm_smart(propose_admin_resp.vote_addr, &VoteQueryMsg::VotesLeft {})
             .unwrap();
 
-        assert_eq!(
-            resp,
-            VotesLeftResp {
-                votes_left: Decimal::zero()
-            }
-        );
+        assert_eq!(resp, VotesLeftResp { votes_left: 0 });
     }
 
     #[test]
@@ -222,10 +239,20 @@ mod tests {
                 admin_code_id,
                 Addr::unchecked("owner"),
                 &AdminInstantiateMsg {
-                    admins: vec![String::from("owner"), String::from("admin1")],
+                    admins: members(&["owner", "admin1"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id,
-                    quorum: Decimal::percent(50),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "vote",
@@ -240,31 +267,47 @@ mod tests {
                 &AdminExecuteMsg::ProposeAdmin {
                     addr: String::from("new_admin"),
                     admin_code_id,
+                    voting_period: None,
                 },
                 &[],
             )
             .unwrap();
 
-        let propose_admin_resp: ProposeAdminResp = from_binary(&resp.data.unwrap()).unwrap();
+        let propose_admin_resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 2);
 
-        app.execute_contract(
-            Addr::unchecked("admin1"),
-            propose_admin_resp.vote_addr,
-            &VoteExecuteMsg::Accept {},
-            &[],
-        )
-        .unwrap();
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                propose_admin_resp.vote_addr,
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+
+        resp.assert_event(&Event::new("wasm").add_attribute("action", "apply_add_admin"));
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin, &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 3);
@@ -286,15 +329,20 @@ mod tests {
                 admin_code_id,
                 Addr::unchecked("owner"),
                 &AdminInstantiateMsg {
-                    admins: vec![
-                        "owner".to_owned(),
-                        "admin1".to_owned(),
-                        "admin2".to_owned(),
-                        "admin3".to_owned(),
-                    ],
+                    admins: members(&["owner", "admin1", "admin2", "admin3"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id,
-                    quorum: Decimal::percent(75),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(75),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "vote",
@@ -309,12 +357,13 @@ mod tests {
                 &AdminExecuteMsg::ProposeAdmin {
                     addr: String::from("new_admin"),
                     admin_code_id,
+                    voting_period: None,
                 },
                 &[],
             )
             .unwrap();
 
-        let propose_admin_resp: ProposeAdminResp = from_binary(&resp.data.unwrap()).unwrap();
+        let propose_admin_resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
 
         app.execute_contract(
             Addr::unchecked("admin1"),
@@ -326,22 +375,42 @@ mod tests {
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 4);
 
-        app.execute_contract(
-            Addr::unchecked("admin1"),
-            propose_admin_resp.vote_addr.clone(),
-            &VoteExecuteMsg::Accept {},
-            &[],
-        )
-        .unwrap();
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                propose_admin_resp.vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            VoteError::AlreadyVoted {
+                voter: Addr::unchecked("admin1")
+            },
+            err.downcast().unwrap()
+        );
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 4);
@@ -356,22 +425,42 @@ mod tests {
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 4);
 
-        app.execute_contract(
-            Addr::unchecked("admin1"),
-            propose_admin_resp.vote_addr.clone(),
-            &VoteExecuteMsg::Accept {},
-            &[],
-        )
-        .unwrap();
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                propose_admin_resp.vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            VoteError::AlreadyVoted {
+                voter: Addr::unchecked("admin1")
+            },
+            err.downcast().unwrap()
+        );
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 4);
@@ -386,7 +475,13 @@ mod tests {
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin, &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 5);
@@ -408,10 +503,20 @@ mod tests {
                 admin_code_id,
                 Addr::unchecked("owner"),
                 &AdminInstantiateMsg {
-                    admins: vec![String::from("owner"), String::from("admin1")],
+                    admins: members(&["owner", "admin1"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id,
-                    quorum: Decimal::percent(50),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "vote",
@@ -426,12 +531,13 @@ mod tests {
                 &AdminExecuteMsg::ProposeAdmin {
                     addr: String::from("admin2"),
                     admin_code_id,
+                    voting_period: None,
                 },
                 &[],
             )
             .unwrap();
 
-        let propose_admin_2_resp: ProposeAdminResp = from_binary(&resp.data.unwrap()).unwrap();
+        let propose_admin_2_resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
 
         let resp: AppResponse = app
             .execute_contract(
@@ -440,16 +546,23 @@ mod tests {
                 &AdminExecuteMsg::ProposeAdmin {
                     addr: String::from("admin3"),
                     admin_code_id,
+                    voting_period: None,
                 },
                 &[],
             )
             .unwrap();
 
-        let propose_admin_3_resp: ProposeAdminResp = from_binary(&resp.data.unwrap()).unwrap();
+        let propose_admin_3_resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 2);
@@ -466,7 +579,13 @@ mod tests {
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 3);
@@ -481,15 +600,21 @@ mod tests {
             .unwrap_err();
 
         assert_eq!(
-            StdError::generic_err(
-                "Admin is not allowed to vote due to being approved after vote is created."
-            ),
+            VoteError::JoinedAfterVoteStart {
+                addr: Addr::unchecked("admin2")
+            },
             err.downcast().unwrap()
         );
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 3);
@@ -504,7 +629,13 @@ mod tests {
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin, &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 4);
@@ -526,14 +657,20 @@ mod tests {
                 admin_code_id,
                 Addr::unchecked("owner"),
                 &AdminInstantiateMsg {
-                    admins: vec![
-                        String::from("owner"),
-                        String::from("admin1"),
-                        String::from("admin2"),
-                    ],
+                    admins: members(&["owner", "admin1", "admin2"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id,
-                    quorum: Decimal::percent(40),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(40),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "vote",
@@ -548,35 +685,52 @@ mod tests {
                 &AdminExecuteMsg::ProposeAdmin {
                     addr: String::from("admin3"),
                     admin_code_id,
+                    voting_period: None,
                 },
                 &[],
             )
             .unwrap();
 
-        let resp: ProposeAdminResp = from_binary(&resp.data.unwrap()).unwrap();
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
         let vote_addr = resp.vote_addr;
 
         let resp: AdminsListResp = app
             .wrap()
-            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
             .unwrap();
 
         assert_eq!(resp.admins.len(), 3);
 
-        app.execute_contract(
-            Addr::unchecked("admin1"),
-            vote_addr,
-            &VoteExecuteMsg::Accept {},
-            &[],
-        )
-        .unwrap();
-
-        let resp: AdminsListResp = app
+        // 40% of three admins is rounded up to two votes
+        let resp: VotesLeftResp = app
             .wrap()
-            .query_wasm_smart(admin, &AdminQueryMsg::AdminsList {})
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::VotesLeft {})
             .unwrap();
 
-        assert_eq!(resp.admins.len(), 4);
+        assert_eq!(resp, VotesLeftResp { votes_left: 2 });
+
+        for (voter, admins_count) in [("admin1", 3), ("admin2", 4)] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+
+            let resp: AdminsCountResp = app
+                .wrap()
+                .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsCount {})
+                .unwrap();
+
+            assert_eq!(resp.count, admins_count);
+        }
     }
 
     #[test]
@@ -595,10 +749,20 @@ mod tests {
                 admin_code_id,
                 Addr::unchecked("owner"),
                 &AdminInstantiateMsg {
-                    admins: vec![String::from("owner"), String::from("admin1")],
+                    admins: members(&["owner", "admin1"]),
                     donation_denom: "eth".to_owned(),
                     vote_code_id,
-                    quorum: Decimal::percent(50),
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
                 },
                 &[],
                 "vote",
@@ -613,12 +777,13 @@ mod tests {
                 &AdminExecuteMsg::ProposeAdmin {
                     addr: String::from("proposed_admin"),
                     admin_code_id,
+                    voting_period: None,
                 },
                 &[],
             )
             .unwrap();
 
-        let resp: ProposeAdminResp = from_binary(&resp.data.unwrap()).unwrap();
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
         let addr = resp.vote_addr;
 
         let resp: VotesLeftResp = app
@@ -626,12 +791,7 @@ mod tests {
             .query_wasm_smart(addr.clone(), &VoteQueryMsg::VotesLeft {})
             .unwrap();
 
-        assert_eq!(
-            resp,
-            VotesLeftResp {
-                votes_left: Decimal::from_str("1.0").unwrap()
-            }
-        );
+        assert_eq!(resp, VotesLeftResp { votes_left: 1 });
 
         let resp: ProposedAdminResp = app
             .wrap()
@@ -645,4 +805,3925 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn reject_vote() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2", "admin3"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(75),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        let resp: AppResponse = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            vote_addr.clone(),
+            &VoteExecuteMsg::Reject {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Open);
+
+        app.execute_contract(
+            Addr::unchecked("admin2"),
+            vote_addr.clone(),
+            &VoteExecuteMsg::Reject {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Rejected);
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin3"),
+                vote_addr,
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(VoteError::VoteClosed {}, err.downcast().unwrap());
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.admins.len(), 4);
+    }
+
+    #[test]
+    fn abstain_vote() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        let resp: AppResponse = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            vote_addr.clone(),
+            &VoteExecuteMsg::Abstain {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Open);
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            vote_addr.clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr, &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Passed);
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.admins.len(), 3);
+    }
+
+    #[test]
+    fn expired_vote() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        let resp: AppResponse = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: Some(Duration::Time(100)),
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Open);
+        assert_eq!(
+            resp.expiration,
+            Expiration::AtTime(app.block_info().time.plus_seconds(100))
+        );
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ClearVote {
+                    vote: vote_addr.to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::VoteOpen {
+                vote: vote_addr.clone()
+            },
+            err.downcast().unwrap()
+        );
+
+        app.update_block(|block| block.time = block.time.plus_seconds(100));
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Expired);
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(VoteError::Expired {}, err.downcast().unwrap());
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                admin.clone(),
+                &AdminExecuteMsg::ClearVote {
+                    vote: vote_addr.to_string(),
+                },
+                &[],
+            )
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::VOTE_CLEARED))
+                .add_attribute(attributes::VOTE, vote_addr.as_str())
+                .add_attribute(attributes::STATUS, "expired"),
+        );
+
+        app.execute_contract(
+            Addr::unchecked("user"),
+            admin.clone(),
+            &AdminExecuteMsg::ClearVote {
+                vote: vote_addr.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.admins.len(), 2);
+    }
+
+    #[test]
+    fn proposal_deposit_refunded_on_pass() {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("owner"), coins(20, "eth"))
+                .unwrap();
+        });
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: Some(ProposalDeposit {
+                        amount: Uint128::new(10),
+                        burn_on_failure: false,
+                    }),
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        let propose = AdminExecuteMsg::ProposeAdmin {
+            addr: String::from("new_admin"),
+            admin_code_id,
+            voting_period: None,
+        };
+
+        let err = app
+            .execute_contract(Addr::unchecked("owner"), admin.clone(), &propose, &[])
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Payment(PaymentError::NoFunds {}),
+            err.downcast().unwrap()
+        );
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &propose,
+                &coins(5, "eth"),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidDeposit {
+                expected: coin(10, "eth")
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp: AppResponse = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &propose,
+                &coins(10, "eth"),
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance("owner", "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            10
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(&admin, "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            10
+        );
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            resp.vote_addr,
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance("owner", "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            20
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(&admin, "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            0
+        );
+    }
+
+    #[test]
+    fn proposal_deposit_on_failure() {
+        for burn_on_failure in [false, true] {
+            let mut app = App::new(|router, _api, storage| {
+                router
+                    .bank
+                    .init_balance(storage, &Addr::unchecked("owner"), coins(10, "eth"))
+                    .unwrap();
+            });
+            let admin_code_id = app.store_code(admin());
+            let vote_code_id = app.store_code(vote());
+
+            let admin = app
+                .instantiate_contract(
+                    admin_code_id,
+                    Addr::unchecked("owner"),
+                    &AdminInstantiateMsg {
+                        admins: members(&["owner", "admin1"]),
+                        donation_denom: "eth".to_owned(),
+                        vote_code_id,
+                        threshold: Threshold::AbsolutePercentage {
+                            percentage: Decimal::percent(50),
+                        },
+                        voting_period: Duration::Time(3600),
+                        proposal_deposit: Some(ProposalDeposit {
+                            amount: Uint128::new(10),
+                            burn_on_failure,
+                        }),
+                        veto: None,
+                        timelock: None,
+                        guardian: None,
+                        min_admins: None,
+                        remainder_policy: None,
+                        donation_assets: vec![],
+                    },
+                    &[],
+                    "vote",
+                    None,
+                )
+                .unwrap();
+
+            let resp: AppResponse = app
+                .execute_contract(
+                    Addr::unchecked("owner"),
+                    admin.clone(),
+                    &AdminExecuteMsg::ProposeAdmin {
+                        addr: String::from("new_admin"),
+                        admin_code_id,
+                        voting_period: None,
+                    },
+                    &coins(10, "eth"),
+                )
+                .unwrap();
+
+            let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+            let vote_addr = resp.vote_addr;
+
+            for voter in ["owner", "admin1"] {
+                app.execute_contract(
+                    Addr::unchecked(voter),
+                    vote_addr.clone(),
+                    &VoteExecuteMsg::Reject {},
+                    &[],
+                )
+                .unwrap();
+            }
+
+            let expected = if burn_on_failure { 0 } else { 10 };
+            assert_eq!(
+                app.wrap()
+                    .query_balance(&admin, "eth")
+                    .unwrap()
+                    .amount
+                    .u128(),
+                expected
+            );
+            assert_eq!(
+                app.wrap()
+                    .query_balance("owner", "eth")
+                    .unwrap()
+                    .amount
+                    .u128(),
+                0
+            );
+        }
+    }
+
+    #[test]
+    fn remove_admin_proposal() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::RemoveAdmin {
+                        addr: String::from("user"),
+                    },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::NotAdmin {
+                addr: Addr::unchecked("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp: AppResponse = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::RemoveAdmin {
+                        addr: String::from("admin2"),
+                    },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        let resp: ActionResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Action {})
+            .unwrap();
+
+        assert_eq!(
+            resp.action,
+            ProposalAction::RemoveAdmin {
+                addr: String::from("admin2")
+            }
+        );
+
+        app.wrap()
+            .query_wasm_smart::<ProposedAdminResp>(
+                vote_addr.clone(),
+                &VoteQueryMsg::ProposedAdmin {},
+            )
+            .unwrap_err();
+
+        // Half of three admins is rounded up
+        for voter in ["owner", "admin1"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp.admins,
+            vec![Addr::unchecked("admin1"), Addr::unchecked("owner")]
+        );
+    }
+
+    #[test]
+    fn config_proposals() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+        let new_vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::UpdateThreshold {
+                        threshold: Threshold::AbsolutePercentage {
+                            percentage: Decimal::percent(150),
+                        },
+                    },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::InvalidPercentage {
+                percentage: Decimal::percent(150)
+            },
+            err.downcast().unwrap()
+        );
+
+        let actions = vec![
+            ProposalAction::UpdateDonationDenom {
+                denom: "atom".to_owned(),
+            },
+            ProposalAction::UpdateVoteCodeId {
+                code_id: new_vote_code_id,
+            },
+            ProposalAction::UpdateThreshold {
+                threshold: Threshold::ThresholdQuorum {
+                    threshold: Decimal::percent(60),
+                    quorum: Decimal::percent(100),
+                },
+            },
+        ];
+
+        for action in actions {
+            let resp: AppResponse = app
+                .execute_contract(
+                    Addr::unchecked("owner"),
+                    admin.clone(),
+                    &AdminExecuteMsg::Propose {
+                        action,
+                        admin_code_id,
+                        voting_period: None,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+            let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+
+            app.execute_contract(
+                Addr::unchecked("admin1"),
+                resp.vote_addr,
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        let resp: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(admin, &AdminQueryMsg::Config {})
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            ConfigResp {
+                donation_denom: "atom".to_owned(),
+                vote_code_id: new_vote_code_id,
+                threshold: Threshold::ThresholdQuorum {
+                    threshold: Decimal::percent(60),
+                    quorum: Decimal::percent(100),
+                },
+                voting_period: Duration::Time(3600),
+                proposal_deposit: None,
+                veto: None,
+                timelock: None,
+                guardian: None,
+                paused: false,
+                min_admins: 1,
+                remainder_policy: RemainderPolicy::RollOver,
+            }
+        );
+    }
+
+    #[test]
+    fn execute_messages_proposal() {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("user"), coins(100, "eth"))
+                .unwrap();
+        });
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        app.send_tokens(Addr::unchecked("user"), admin.clone(), &coins(100, "eth"))
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::Execute { msgs: vec![] },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::NoMessages {}, err.downcast().unwrap());
+
+        let resp: AppResponse = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::Execute {
+                        msgs: vec![BankMsg::Send {
+                            to_address: "recipient".to_owned(),
+                            amount: coins(40, "eth"),
+                        }
+                        .into()],
+                    },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance("recipient", "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            0
+        );
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            resp.vote_addr,
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance("recipient", "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            40
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(&admin, "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            60
+        );
+    }
+
+    #[test]
+    fn voters_snapshot() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(100),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        let resp: AppResponse = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        app.execute_contract(
+            Addr::unchecked("admin2"),
+            admin.clone(),
+            &AdminExecuteMsg::Leave {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: VotersResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Voters {})
+            .unwrap();
+
+        // Departed admin doesn't count towards the vote anymore
+        assert_eq!(
+            resp.voters,
+            vec![Addr::unchecked("admin1"), Addr::unchecked("owner")]
+        );
+
+        let resp: VotesLeftResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::VotesLeft {})
+            .unwrap();
+
+        assert_eq!(resp, VotesLeftResp { votes_left: 2 });
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            VoteError::NotAdmin {
+                addr: Addr::unchecked("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin2"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            VoteError::NotAdmin {
+                addr: Addr::unchecked("admin2")
+            },
+            err.downcast().unwrap()
+        );
+
+        for voter in ["owner", "admin1"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp.admins,
+            vec![
+                Addr::unchecked("admin1"),
+                Addr::unchecked("new_admin"),
+                Addr::unchecked("owner")
+            ]
+        );
+    }
+
+    #[test]
+    fn rewards_follow_admins() {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("user"), coins(13, "eth"))
+                .unwrap();
+        });
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("user"),
+            admin.clone(),
+            &AdminExecuteMsg::Donate {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+
+        let resp: AppResponse = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            resp.vote_addr,
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: PendingRewardsResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::PendingRewards {
+                    admin: String::from("new_admin"),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.rewards, vec![]);
+
+        app.execute_contract(
+            Addr::unchecked("user"),
+            admin.clone(),
+            &AdminExecuteMsg::Donate {},
+            &coins(3, "eth"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            admin.clone(),
+            &AdminExecuteMsg::Leave {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance("admin1", "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            6
+        );
+
+        for (admin_addr, expected) in [("owner", 6), ("new_admin", 1), ("admin1", 0)] {
+            let resp: PendingRewardsResp = app
+                .wrap()
+                .query_wasm_smart(
+                    admin.clone(),
+                    &AdminQueryMsg::PendingRewards {
+                        admin: String::from(admin_addr),
+                    },
+                )
+                .unwrap();
+
+            let pending: u128 = resp.rewards.iter().map(|c| c.amount.u128()).sum();
+            assert_eq!(pending, expected);
+        }
+    }
+
+    #[test]
+    fn multi_asset_donations() {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    &Addr::unchecked("user"),
+                    vec![coin(10, "eth"), coin(10, "btc"), coin(10, "doge")],
+                )
+                .unwrap();
+        });
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![
+                        AssetInfo::Native("btc".to_owned()),
+                        AssetInfo::Cw20("token".to_owned()),
+                    ],
+                },
+                &[],
+                "vote",
+                None,
+            )
+            .unwrap();
+
+        let resp: DonationAssetsResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::DonationAssets {})
+            .unwrap();
+
+        assert_eq!(
+            resp.assets,
+            vec![
+                AssetInfo::Native("eth".to_owned()),
+                AssetInfo::Native("btc".to_owned()),
+                AssetInfo::Cw20("token".to_owned()),
+            ]
+        );
+
+        app.execute_contract(
+            Addr::unchecked("user"),
+            admin.clone(),
+            &AdminExecuteMsg::Donate {},
+            &[coin(4, "btc"), coin(6, "eth")],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                admin.clone(),
+                &AdminExecuteMsg::Donate {},
+                &coins(5, "doge"),
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::AssetNotAccepted {
+                asset: "native:doge".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+
+        // Cw20 contract notifies about tokens sent to the admin contract
+        app.execute_contract(
+            Addr::unchecked("token"),
+            admin.clone(),
+            &AdminExecuteMsg::Receive(Cw20ReceiveMsg {
+                sender: String::from("user"),
+                amount: Uint128::new(8),
+                msg: to_json_binary(&ReceiveMsg::Donate {}).unwrap(),
+            }),
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("other_token"),
+                admin.clone(),
+                &AdminExecuteMsg::Receive(Cw20ReceiveMsg {
+                    sender: String::from("user"),
+                    amount: Uint128::new(8),
+                    msg: to_json_binary(&ReceiveMsg::Donate {}).unwrap(),
+                }),
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::AssetNotAccepted {
+                asset: "cw20:other_token".to_owned()
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp: DonationsResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Donations {})
+            .unwrap();
+
+        assert_eq!(
+            resp.donations,
+            vec![
+                Asset {
+                    info: AssetInfo::Cw20("token".to_owned()),
+                    amount: Uint128::new(8),
+                },
+                Asset {
+                    info: AssetInfo::Native("btc".to_owned()),
+                    amount: Uint128::new(4),
+                },
+                Asset {
+                    info: AssetInfo::Native("eth".to_owned()),
+                    amount: Uint128::new(6),
+                },
+            ]
+        );
+
+        let resp: PendingRewardsResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::PendingRewards {
+                    admin: String::from("admin1"),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp.rewards,
+            vec![
+                Asset {
+                    info: AssetInfo::Cw20("token".to_owned()),
+                    amount: Uint128::new(4),
+                },
+                Asset {
+                    info: AssetInfo::Native("btc".to_owned()),
+                    amount: Uint128::new(2),
+                },
+                Asset {
+                    info: AssetInfo::Native("eth".to_owned()),
+                    amount: Uint128::new(3),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn migration() {
+        let mut app = App::default();
+
+        let admin_code_id = app.store_code(Box::new(
+            ContractWrapper::new(admin_execute, admin_instantiate, admin_query)
+                .with_reply(admin_reply)
+                .with_migrate(admin_migrate),
+        ));
+        let vote_code_id = app.store_code(Box::new(
+            ContractWrapper::new(vote_execute, vote_instantiate, vote_query)
+                .with_migrate(vote_migrate),
+        ));
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                Some(String::from("owner")),
+            )
+            .unwrap();
+
+        let version = query_contract_info(&app, admin.clone()).unwrap();
+        assert_eq!(version.contract, "crates.io:contract-admin");
+        assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+
+        app.migrate_contract(
+            Addr::unchecked("owner"),
+            admin.clone(),
+            &AdminMigrateMsg {
+                voting_period: None,
+            },
+            admin_code_id,
+        )
+        .unwrap();
+
+        let err = app
+            .migrate_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &VoteMigrateMsg {},
+                vote_code_id,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            VoteError::Version(VersionError::InvalidContract {
+                contract: "crates.io:contract-admin".to_owned()
+            }),
+            err.downcast().unwrap()
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("admin1"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote = resp.vote_addr;
+
+        // Only the admin contract is allowed to migrate its votes
+        app.migrate_contract(
+            Addr::unchecked("owner"),
+            vote.clone(),
+            &VoteMigrateMsg {},
+            vote_code_id,
+        )
+        .unwrap_err();
+
+        app.migrate_contract(
+            admin.clone(),
+            vote.clone(),
+            &VoteMigrateMsg {},
+            vote_code_id,
+        )
+        .unwrap();
+
+        let version = query_contract_info(&app, vote).unwrap();
+        assert_eq!(version.contract, "crates.io:contract-vote");
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.admins, vec![Addr::unchecked("owner")]);
+    }
+
+    #[test]
+    fn paginated_admins() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["admin3", "admin1", "admin2", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let resp: AdminsCountResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::AdminsCount {})
+            .unwrap();
+
+        assert_eq!(resp.count, 3);
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp.admins,
+            vec![Addr::unchecked("admin1"), Addr::unchecked("admin2")]
+        );
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: Some(String::from("admin2")),
+                    limit: Some(2),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.admins, vec![Addr::unchecked("admin3")]);
+
+        let joined = app.block_info().time;
+        let resp: AdminsResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::Admins {
+                    start_after: Some(String::from("admin1")),
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp.admins,
+            vec![
+                AdminEntry {
+                    addr: Addr::unchecked("admin2"),
+                    joined,
+                    weight: 1,
+                },
+                AdminEntry {
+                    addr: Addr::unchecked("admin3"),
+                    joined,
+                    weight: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn proposal_registry() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let mut votes = vec![];
+        for (proposer, addr, voting_period) in [
+            ("owner", "new_admin", Some(Duration::Time(10))),
+            ("admin1", "admin2", None),
+            ("owner", "admin3", None),
+        ] {
+            let resp = app
+                .execute_contract(
+                    Addr::unchecked(proposer),
+                    admin.clone(),
+                    &AdminExecuteMsg::ProposeAdmin {
+                        addr: String::from(addr),
+                        admin_code_id,
+                        voting_period,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+            let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+            assert_eq!(resp.proposal_id, votes.len() as u64 + 1);
+            votes.push(resp.vote_addr);
+        }
+
+        let created = app.block_info().time;
+        let resp: ProposalListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::ListProposals {
+                    status: Some(VoteStatus::Open),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.proposals.len(), 3);
+        assert_eq!(
+            resp.proposals[1],
+            ProposalResp {
+                id: 2,
+                proposer: Addr::unchecked("admin1"),
+                action: ProposalAction::AddAdmin {
+                    addr: String::from("admin2"),
+                },
+                vote: votes[1].clone(),
+                created,
+                expires: Expiration::AtTime(created.plus_seconds(3600)),
+                status: VoteStatus::Open,
+                executable_at: None,
+            }
+        );
+
+        for voter in ["owner", "admin1"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                votes[1].clone(),
+                &VoteExecuteMsg::Reject {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            votes[2].clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(20));
+
+        let resp: ProposalResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Proposal { id: 1 })
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Expired);
+
+        let resp: ProposalListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::ListProposals {
+                    status: None,
+                    start_after: Some(1),
+                    limit: Some(1),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.proposals.len(), 1);
+        assert_eq!(resp.proposals[0].id, 2);
+        assert_eq!(resp.proposals[0].status, VoteStatus::Rejected);
+        assert_eq!(resp.last_id, Some(2));
+
+        let resp: ProposalListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::ListProposals {
+                    status: Some(VoteStatus::Passed),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.proposals.len(), 1);
+        assert_eq!(resp.proposals[0].id, 3);
+        assert_eq!(resp.last_id, Some(3));
+
+        let resp: ProposalListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::ListProposals {
+                    status: Some(VoteStatus::Open),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.proposals, vec![]);
+    }
+
+    #[test]
+    fn vote_finished_callbacks() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let mut votes = vec![];
+        for addr in ["admin2", "admin3"] {
+            let resp = app
+                .execute_contract(
+                    Addr::unchecked("owner"),
+                    admin.clone(),
+                    &AdminExecuteMsg::ProposeAdmin {
+                        addr: String::from(addr),
+                        admin_code_id,
+                        voting_period: None,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+            let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+            votes.push(resp.vote_addr);
+        }
+
+        for voter in ["owner", "admin1"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                votes[0].clone(),
+                &VoteExecuteMsg::Reject {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        let resp: ProposalResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Proposal { id: 1 })
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Rejected);
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                votes[1].clone(),
+                &VoteExecuteMsg::Close {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(VoteError::VotingPeriodNotEnded {}, err.downcast().unwrap());
+
+        app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+        app.execute_contract(
+            Addr::unchecked("user"),
+            votes[1].clone(),
+            &VoteExecuteMsg::Close {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(votes[1].clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Expired);
+
+        // Outcome is already known to the admin contract, nothing left to clear
+        app.execute_contract(
+            Addr::unchecked("user"),
+            admin.clone(),
+            &AdminExecuteMsg::ClearVote {
+                vote: votes[1].to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+        let resp: ProposalListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::ListProposals {
+                    status: Some(VoteStatus::Expired),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp.proposals.len(), 1);
+        assert_eq!(resp.proposals[0].id, 2);
+    }
+
+    #[test]
+    fn threshold_quorum_vote() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: Decimal::percent(60),
+            quorum: Decimal::percent(50),
+        };
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2", "admin3"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: threshold.clone(),
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let mut votes = vec![];
+        for addr in ["new_admin", "other_admin"] {
+            let resp = app
+                .execute_contract(
+                    Addr::unchecked("owner"),
+                    admin.clone(),
+                    &AdminExecuteMsg::ProposeAdmin {
+                        addr: String::from(addr),
+                        admin_code_id,
+                        voting_period: None,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+            let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+            votes.push(resp.vote_addr);
+        }
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            votes[0].clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            votes[0].clone(),
+            &VoteExecuteMsg::Abstain {},
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            votes[1].clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: TallyResp = app
+            .wrap()
+            .query_wasm_smart(votes[0].clone(), &VoteQueryMsg::Tally {})
+            .unwrap();
+
+        assert_eq!(
+            resp,
+            TallyResp {
+                threshold,
+                total_weight: 4,
+                yes: 1,
+                no: 0,
+                abstain: 1,
+            }
+        );
+
+        // Missing votes are counted as rejecting until the vote expires
+        let resp: VotesLeftResp = app
+            .wrap()
+            .query_wasm_smart(votes[0].clone(), &VoteQueryMsg::VotesLeft {})
+            .unwrap();
+
+        assert_eq!(resp, VotesLeftResp { votes_left: 1 });
+
+        app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+        // Passing vote has to be closed to apply the proposal, it can't be just cleared
+        let err = app
+            .execute_contract(
+                Addr::unchecked("griefer"),
+                admin.clone(),
+                &AdminExecuteMsg::ClearVote {
+                    vote: votes[0].to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::VoteOpen {
+                vote: votes[0].clone()
+            },
+            err.downcast().unwrap()
+        );
+
+        // Expired votes report their outcome before they are closed
+        let resp: ProposalListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::ListProposals {
+                    status: None,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        let statuses: Vec<_> = resp
+            .proposals
+            .into_iter()
+            .map(|proposal| (proposal.vote, proposal.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (votes[0].clone(), VoteStatus::Passed),
+                (votes[1].clone(), VoteStatus::Expired)
+            ]
+        );
+
+        // Quorum is reached and the only opinion accepts the proposal
+        for vote_addr in &votes {
+            app.execute_contract(
+                Addr::unchecked("user"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Close {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(votes[0].clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Passed);
+
+        // Single vote doesn't reach the quorum
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(votes[1].clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Expired);
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert!(resp.admins.contains(&Addr::unchecked("new_admin")));
+        assert!(!resp.admins.contains(&Addr::unchecked("other_admin")));
+    }
+
+    #[test]
+    fn weighted_voting() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: vec![
+                        Member {
+                            addr: String::from("owner"),
+                            weight: 3,
+                        },
+                        Member {
+                            addr: String::from("admin1"),
+                            weight: 1,
+                        },
+                        Member {
+                            addr: String::from("admin2"),
+                            weight: 1,
+                        },
+                    ],
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(60),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let resp: VotingPowerResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::VotingPower {
+                    admin: String::from("owner"),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp, VotingPowerResp { weight: 3 });
+
+        let resp: TotalPowerResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::TotalPower {})
+            .unwrap();
+
+        assert_eq!(resp, TotalPowerResp { weight: 5 });
+
+        // Owner alone holds enough weight to pass the proposal
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::UpdateWeight {
+                        addr: String::from("admin1"),
+                        weight: 4,
+                    },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        let resp: VotesLeftResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::VotesLeft {})
+            .unwrap();
+
+        assert_eq!(resp, VotesLeftResp { votes_left: 3 });
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            vote_addr.clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr, &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Passed);
+
+        let resp: VotingPowerResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::VotingPower {
+                    admin: String::from("admin1"),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp, VotingPowerResp { weight: 4 });
+
+        let resp: TotalPowerResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::TotalPower {})
+            .unwrap();
+
+        assert_eq!(resp, TotalPowerResp { weight: 8 });
+
+        // Non admins have no voting power
+        let resp: VotingPowerResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::VotingPower {
+                    admin: String::from("user"),
+                },
+            )
+            .unwrap();
+
+        assert_eq!(resp, VotingPowerResp { weight: 0 });
+    }
+
+    #[test]
+    fn change_ballot() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2", "admin3"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(75),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            vote_addr.clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Reject {},
+                &[],
+            )
+            .unwrap();
+
+        resp.assert_event(
+            &Event::new("wasm-ballot_cast")
+                .add_attribute("voter", "admin1")
+                .add_attribute("ballot", "reject"),
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+
+        resp.assert_event(
+            &Event::new("wasm-ballot_changed")
+                .add_attribute("voter", "admin1")
+                .add_attribute("previous", "reject")
+                .add_attribute("ballot", "accept"),
+        );
+
+        let resp: TallyResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Tally {})
+            .unwrap();
+
+        assert_eq!((resp.yes, resp.no, resp.abstain), (2, 0, 0));
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Retract {},
+                &[],
+            )
+            .unwrap();
+
+        resp.assert_event(
+            &Event::new("wasm-ballot_retracted")
+                .add_attribute("voter", "admin1")
+                .add_attribute("ballot", "accept"),
+        );
+
+        let resp: TallyResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Tally {})
+            .unwrap();
+
+        assert_eq!((resp.yes, resp.no, resp.abstain), (1, 0, 0));
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Retract {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            VoteError::NoBallot {
+                voter: Addr::unchecked("admin1")
+            },
+            err.downcast().unwrap()
+        );
+
+        for voter in ["admin1", "admin2"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Passed);
+
+        // Ballots are final once the vote is closed
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                vote_addr,
+                &VoteExecuteMsg::Retract {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(VoteError::VoteClosed {}, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn cancel_and_veto() {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("owner"), coins(20, "eth"))
+                .unwrap();
+        });
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(100),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: Some(ProposalDeposit {
+                        amount: Uint128::new(10),
+                        burn_on_failure: false,
+                    }),
+                    veto: Some(String::from("guardian")),
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let propose = AdminExecuteMsg::ProposeAdmin {
+            addr: String::from("new_admin"),
+            admin_code_id,
+            voting_period: None,
+        };
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &propose,
+                &coins(10, "eth"),
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let (cancelled_id, cancelled) = (resp.proposal_id, resp.vote_addr);
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                cancelled.clone(),
+                &VoteExecuteMsg::Cancel {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            VoteError::NotProposer {
+                sender: Addr::unchecked("admin1")
+            },
+            err.downcast().unwrap()
+        );
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            cancelled.clone(),
+            &VoteExecuteMsg::Cancel {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(cancelled, &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Cancelled);
+
+        let resp: ProposalResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Proposal { id: cancelled_id })
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Cancelled);
+
+        // Deposit is refunded as nobody voted yet
+        assert_eq!(
+            app.wrap()
+                .query_balance("owner", "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            20
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &propose,
+                &coins(10, "eth"),
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let (vetoed_id, vetoed) = (resp.proposal_id, resp.vote_addr);
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            vetoed.clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                vetoed.clone(),
+                &VoteExecuteMsg::Cancel {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(VoteError::BallotsCast {}, err.downcast().unwrap());
+
+        let veto = AdminExecuteMsg::Veto {
+            vote: vetoed.to_string(),
+        };
+
+        let err = app
+            .execute_contract(Addr::unchecked("admin1"), admin.clone(), &veto, &[])
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: Addr::unchecked("admin1")
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp = app
+            .execute_contract(Addr::unchecked("guardian"), admin.clone(), &veto, &[])
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::VOTE_VETOED))
+                .add_attribute(attributes::VOTE, vetoed.as_str()),
+        );
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vetoed.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Vetoed);
+
+        let resp: ProposalResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Proposal { id: vetoed_id })
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Vetoed);
+
+        // Vetoed proposal loses its deposit
+        assert_eq!(
+            app.wrap()
+                .query_balance("owner", "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            10
+        );
+        assert_eq!(
+            app.wrap()
+                .query_balance(&admin, "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            10
+        );
+
+        let err = app
+            .execute_contract(Addr::unchecked("guardian"), admin.clone(), &veto, &[])
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::NoPendingVote {
+                vote: vetoed.clone()
+            },
+            err.downcast().unwrap()
+        );
+
+        // Closed vote can't report its outcome again
+        let err = app
+            .execute_contract(
+                vetoed.clone(),
+                admin.clone(),
+                &AdminExecuteMsg::VoteFinished {
+                    outcome: VoteOutcome::Passed,
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::NoPendingVote {
+                vote: vetoed.clone()
+            },
+            err.downcast().unwrap()
+        );
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ClearVote {
+                    vote: vetoed.to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::NoPendingVote { vote: vetoed },
+            err.downcast().unwrap()
+        );
+
+        // The veto can't block its own removal
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::UpdateVeto { veto: None },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &coins(10, "eth"),
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let err = app
+            .execute_contract(
+                Addr::unchecked("guardian"),
+                admin,
+                &AdminExecuteMsg::Veto {
+                    vote: resp.vote_addr.to_string(),
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::VetoExempt {
+                vote: resp.vote_addr
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn timelocked_execution() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: Some(String::from("guardian")),
+                    timelock: Some(600),
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let mut proposals = vec![];
+        for addr in ["new_admin", "malicious_admin"] {
+            let resp = app
+                .execute_contract(
+                    Addr::unchecked("owner"),
+                    admin.clone(),
+                    &AdminExecuteMsg::ProposeAdmin {
+                        addr: String::from(addr),
+                        admin_code_id,
+                        voting_period: None,
+                    },
+                    &[],
+                )
+                .unwrap();
+
+            let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+
+            let accepted = app
+                .execute_contract(
+                    Addr::unchecked("owner"),
+                    resp.vote_addr.clone(),
+                    &VoteExecuteMsg::Accept {},
+                    &[],
+                )
+                .unwrap();
+            accepted.assert_event(
+                &Event::new(format!("wasm-{}", events::VOTE_QUEUED))
+                    .add_attribute(attributes::PROPOSAL_ID, resp.proposal_id.to_string())
+                    .add_attribute(attributes::VOTE, resp.vote_addr.as_str()),
+            );
+
+            proposals.push((resp.proposal_id, resp.vote_addr));
+        }
+
+        let executable_at = app.block_info().time.plus_seconds(600);
+        let (id, vote_addr) = proposals[0].clone();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Queued);
+        assert_eq!(resp.executable_at, Some(executable_at));
+
+        let resp: ProposalResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Proposal { id })
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Queued);
+        assert_eq!(resp.executable_at, Some(executable_at));
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Execute {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            VoteError::Timelocked { executable_at },
+            err.downcast().unwrap()
+        );
+
+        // The group reacts to the malicious proposal while it waits for the timelock
+        app.execute_contract(
+            Addr::unchecked("guardian"),
+            admin.clone(),
+            &AdminExecuteMsg::Veto {
+                vote: proposals[1].1.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.update_block(|block| block.time = block.time.plus_seconds(600));
+
+        app.execute_contract(
+            Addr::unchecked("user"),
+            vote_addr.clone(),
+            &VoteExecuteMsg::Execute {},
+            &[],
+        )
+        .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                proposals[1].1.clone(),
+                &VoteExecuteMsg::Execute {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(VoteError::NotQueued {}, err.downcast().unwrap());
+
+        let resp: ProposalListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::ListProposals {
+                    status: None,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        let statuses: Vec<_> = resp.proposals.into_iter().map(|p| p.status).collect();
+        assert_eq!(statuses, vec![VoteStatus::Passed, VoteStatus::Vetoed]);
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin,
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert!(resp.admins.contains(&Addr::unchecked("new_admin")));
+        assert!(!resp.admins.contains(&Addr::unchecked("malicious_admin")));
+    }
+
+    #[test]
+    fn emergency_pause() {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("owner"), coins(20, "eth"))
+                .unwrap();
+        });
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2", "admin3"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: Some(String::from("guardian")),
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                admin.clone(),
+                &AdminExecuteMsg::SetPaused { paused: true },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: Addr::unchecked("admin1")
+            },
+            err.downcast().unwrap()
+        );
+
+        // Opened before the pause, passes only once the contract is unpaused
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("late_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let late_vote = resp.vote_addr;
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            late_vote.clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("guardian"),
+                admin.clone(),
+                &AdminExecuteMsg::SetPaused { paused: true },
+                &[],
+            )
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::PAUSE_CHANGED))
+                .add_attribute(attributes::PAUSED, "true"),
+        );
+
+        let resp: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Config {})
+            .unwrap();
+
+        assert!(resp.paused);
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                late_vote.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::Paused {}, err.downcast().unwrap());
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(late_vote.clone(), &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Open);
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Donate {},
+                &coins(10, "eth"),
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::Paused {}, err.downcast().unwrap());
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::Paused {}, err.downcast().unwrap());
+
+        // Unpausing needs two thirds of the weight instead of the configured half
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::SetPaused { paused: false },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        for voter in ["owner", "admin1"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        let resp: VotesLeftResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr.clone(), &VoteQueryMsg::VotesLeft {})
+            .unwrap();
+
+        assert_eq!(resp, VotesLeftResp { votes_left: 1 });
+
+        app.execute_contract(
+            Addr::unchecked("admin2"),
+            vote_addr,
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Config {})
+            .unwrap();
+
+        assert!(!resp.paused);
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            late_vote,
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            admin.clone(),
+            &AdminExecuteMsg::Donate {},
+            &coins(10, "eth"),
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("guardian"),
+            admin.clone(),
+            &AdminExecuteMsg::SetPaused { paused: true },
+            &[],
+        )
+        .unwrap();
+
+        // Admins can still leave with their rewards
+        app.execute_contract(
+            Addr::unchecked("admin3"),
+            admin.clone(),
+            &AdminExecuteMsg::Leave {},
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(
+            app.wrap()
+                .query_balance("admin3", "eth")
+                .unwrap()
+                .amount
+                .u128(),
+            2
+        );
+
+        let resp: AdminsCountResp = app
+            .wrap()
+            .query_wasm_smart(admin, &AdminQueryMsg::AdminsCount {})
+            .unwrap();
+
+        assert_eq!(resp, AdminsCountResp { count: 4 });
+    }
+
+    #[test]
+    fn leave_while_paused() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(100),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: Some(String::from("guardian")),
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        for voter in ["owner", "admin1"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        app.execute_contract(
+            Addr::unchecked("guardian"),
+            admin.clone(),
+            &AdminExecuteMsg::SetPaused { paused: true },
+            &[],
+        )
+        .unwrap();
+
+        // Dropping the ballot would pass the vote, which can't be applied while paused
+        app.execute_contract(
+            Addr::unchecked("admin2"),
+            admin.clone(),
+            &AdminExecuteMsg::Leave {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr, &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Open);
+
+        let resp: AdminsCountResp = app
+            .wrap()
+            .query_wasm_smart(admin, &AdminQueryMsg::AdminsCount {})
+            .unwrap();
+
+        assert_eq!(resp, AdminsCountResp { count: 2 });
+    }
+
+    #[test]
+    fn hostile_guardian() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1", "admin2"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(50),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: Some(String::from("guardian")),
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::UpdateGuardian { guardian: None },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            vote_addr.clone(),
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("guardian"),
+            admin.clone(),
+            &AdminExecuteMsg::SetPaused { paused: true },
+            &[],
+        )
+        .unwrap();
+
+        // Guardian can be replaced while paused
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            admin.clone(),
+            &AdminExecuteMsg::Propose {
+                action: ProposalAction::UpdateGuardian {
+                    guardian: Some(String::from("new_guardian")),
+                },
+                admin_code_id,
+                voting_period: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        app.execute_contract(
+            Addr::unchecked("admin1"),
+            vote_addr,
+            &VoteExecuteMsg::Accept {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: ConfigResp = app
+            .wrap()
+            .query_wasm_smart(admin.clone(), &AdminQueryMsg::Config {})
+            .unwrap();
+
+        assert!(resp.paused);
+        assert_eq!(resp.guardian, None);
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("guardian"),
+                admin,
+                &AdminExecuteMsg::SetPaused { paused: true },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::Unauthorized {
+                sender: Addr::unchecked("guardian")
+            },
+            err.downcast().unwrap()
+        );
+    }
+
+    #[test]
+    fn unreachable_count_threshold() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let mut msg = AdminInstantiateMsg {
+            admins: members(&["owner", "admin1", "admin2"]),
+            donation_denom: "eth".to_owned(),
+            vote_code_id,
+            threshold: Threshold::AbsoluteCount { weight: 10 },
+            voting_period: Duration::Time(3600),
+            proposal_deposit: None,
+            veto: None,
+            timelock: None,
+            guardian: None,
+            min_admins: None,
+            remainder_policy: None,
+            donation_assets: vec![],
+        };
+
+        let err = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &msg,
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::UnreachableWeight {
+                weight: 10,
+                total_weight: 3
+            },
+            err.downcast().unwrap()
+        );
+
+        msg.threshold = Threshold::AbsoluteCount { weight: 3 };
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &msg,
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("admin2"),
+                admin.clone(),
+                &AdminExecuteMsg::Leave {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::UnreachableWeight {
+                weight: 3,
+                total_weight: 2
+            },
+            err.downcast().unwrap()
+        );
+
+        let actions = [
+            (
+                ProposalAction::RemoveAdmin {
+                    addr: String::from("admin1"),
+                },
+                2,
+            ),
+            (
+                ProposalAction::UpdateWeight {
+                    addr: String::from("admin1"),
+                    weight: 0,
+                },
+                2,
+            ),
+            (
+                ProposalAction::UpdateThreshold {
+                    threshold: Threshold::AbsoluteCount { weight: 4 },
+                },
+                3,
+            ),
+        ];
+        for (action, total_weight) in actions {
+            let weight = match &action {
+                ProposalAction::UpdateThreshold {
+                    threshold: Threshold::AbsoluteCount { weight },
+                } => *weight,
+                _ => 3,
+            };
+
+            let err = app
+                .execute_contract(
+                    Addr::unchecked("owner"),
+                    admin.clone(),
+                    &AdminExecuteMsg::Propose {
+                        action,
+                        admin_code_id,
+                        voting_period: None,
+                    },
+                    &[],
+                )
+                .unwrap_err();
+
+            assert_eq!(
+                ContractError::UnreachableWeight {
+                    weight,
+                    total_weight
+                },
+                err.downcast().unwrap()
+            );
+        }
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::UpdateThreshold {
+                        threshold: Threshold::AbsoluteCount { weight: 2 },
+                    },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        for voter in ["owner", "admin1", "admin2"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                resp.vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        app.execute_contract(
+            Addr::unchecked("admin2"),
+            admin,
+            &AdminExecuteMsg::Leave {},
+            &[],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn min_admins() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let mut msg = AdminInstantiateMsg {
+            admins: members(&["owner", "admin1", "admin2"]),
+            donation_denom: "eth".to_owned(),
+            vote_code_id,
+            threshold: Threshold::AbsolutePercentage {
+                percentage: Decimal::percent(100),
+            },
+            voting_period: Duration::Time(3600),
+            proposal_deposit: None,
+            veto: None,
+            timelock: None,
+            guardian: None,
+            min_admins: Some(0),
+            remainder_policy: None,
+            donation_assets: vec![],
+        };
+
+        let err = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &msg,
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::ZeroMinAdmins {}, err.downcast().unwrap());
+
+        msg.min_admins = Some(2);
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &msg,
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                admin.clone(),
+                &AdminExecuteMsg::Leave {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(
+            ContractError::NotAdmin {
+                addr: Addr::unchecked("user")
+            },
+            err.downcast().unwrap()
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let vote_addr = resp.vote_addr;
+
+        for voter in ["owner", "admin1"] {
+            app.execute_contract(
+                Addr::unchecked(voter),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        }
+
+        // Remaining voters already accepted, so the vote passes once the last one leaves
+        app.execute_contract(
+            Addr::unchecked("admin2"),
+            admin.clone(),
+            &AdminExecuteMsg::Leave {},
+            &[],
+        )
+        .unwrap();
+
+        let resp: StatusResp = app
+            .wrap()
+            .query_wasm_smart(vote_addr, &VoteQueryMsg::Status {})
+            .unwrap();
+
+        assert_eq!(resp.status, VoteStatus::Passed);
+
+        let resp: AdminsListResp = app
+            .wrap()
+            .query_wasm_smart(
+                admin.clone(),
+                &AdminQueryMsg::AdminsList {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            resp.admins,
+            vec![
+                Addr::unchecked("admin1"),
+                Addr::unchecked("new_admin"),
+                Addr::unchecked("owner")
+            ]
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("late_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let expired_vote = resp.vote_addr;
+
+        app.update_block(|block| block.time = block.time.plus_seconds(3600));
+
+        // Expired vote is not told about the leaving admin
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                admin.clone(),
+                &AdminExecuteMsg::Leave {},
+                &[],
+            )
+            .unwrap();
+
+        assert!(!resp.events.iter().any(|event| event
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "_contract_addr" && attr.value == expired_vote.as_str())));
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::Leave {},
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::MinAdmins { min: 2 }, err.downcast().unwrap());
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin,
+                &AdminExecuteMsg::Propose {
+                    action: ProposalAction::RemoveAdmin {
+                        addr: String::from("new_admin"),
+                    },
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap_err();
+
+        assert_eq!(ContractError::MinAdmins { min: 2 }, err.downcast().unwrap());
+    }
+
+    #[test]
+    fn structured_events() {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor"), coins(11, "eth"))
+                .unwrap();
+        });
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(100),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: Some(RemainderPolicy::RollOver),
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+
+        let data: ProposeAdminResp = from_json(resp.data.as_ref().unwrap()).unwrap();
+        let vote_addr = data.vote_addr;
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::PROPOSAL_CREATED))
+                .add_attribute(attributes::PROPOSAL_ID, "1")
+                .add_attribute(attributes::PROPOSER, "owner")
+                .add_attribute(attributes::VOTE, vote_addr.as_str()),
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::BALLOT_CAST))
+                .add_attribute(attributes::VOTER, "owner")
+                .add_attribute(attributes::BALLOT, "accept")
+                .add_attribute(attributes::WEIGHT, "1"),
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                vote_addr.clone(),
+                &VoteExecuteMsg::Accept {},
+                &[],
+            )
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::VOTE_PASSED))
+                .add_attribute(attributes::PROPOSAL_ID, "1")
+                .add_attribute(attributes::VOTE, vote_addr.as_str()),
+        );
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::ADMIN_ADDED))
+                .add_attribute(attributes::ADMIN, "new_admin")
+                .add_attribute(attributes::WEIGHT, "1"),
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("donor"),
+                admin.clone(),
+                &AdminExecuteMsg::Donate {},
+                &coins(6, "eth"),
+            )
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::DONATION_SPLIT))
+                .add_attribute(attributes::DONOR, "donor")
+                .add_attribute(attributes::ASSET, "native:eth")
+                .add_attribute(attributes::AMOUNT, "6")
+                .add_attribute(attributes::PER_ADMIN, "2")
+                .add_attribute(attributes::REMAINDER, "0")
+                .add_attribute(attributes::CARRIED, "0"),
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("admin1"),
+                admin.clone(),
+                &AdminExecuteMsg::Leave {},
+                &[],
+            )
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::ADMIN_LEFT))
+                .add_attribute(attributes::ADMIN, "admin1"),
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("donor"),
+                admin.clone(),
+                &AdminExecuteMsg::Donate {},
+                &coins(5, "eth"),
+            )
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::DONATION_SPLIT))
+                .add_attribute(attributes::AMOUNT, "5")
+                .add_attribute(attributes::PER_ADMIN, "2")
+                .add_attribute(attributes::REMAINDER, "0")
+                .add_attribute(attributes::CARRIED, "1"),
+        );
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin,
+                &AdminExecuteMsg::ClaimRewards {},
+                &[],
+            )
+            .unwrap();
+        resp.assert_event(
+            &Event::new(format!("wasm-{}", events::REWARDS_CLAIMED))
+                .add_attribute(attributes::ADMIN, "owner")
+                .add_attribute(attributes::ASSET, "native:eth")
+                .add_attribute(attributes::AMOUNT, "4"),
+        );
+    }
+
+    #[test]
+    fn contract_clients() {
+        let mut app = App::new(|router, _api, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked("donor"), coins(4, "eth"))
+                .unwrap();
+        });
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(100),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+        let admin = AdminContract(admin);
+
+        app.execute(
+            Addr::unchecked("owner"),
+            admin
+                .propose_admin_msg("new_admin", admin_code_id, None)
+                .unwrap(),
+        )
+        .unwrap();
+        let vote = VoteContract(admin.proposal(&app.wrap(), 1).unwrap().vote);
+
+        app.execute(Addr::unchecked("owner"), vote.accept_msg().unwrap())
+            .unwrap();
+        assert_eq!(
+            vote.votes_left(&app.wrap()).unwrap(),
+            VotesLeftResp { votes_left: 1 }
+        );
+        assert_eq!(
+            vote.proposed_admin(&app.wrap()).unwrap().proposed_admin,
+            Addr::unchecked("new_admin")
+        );
+
+        app.execute(Addr::unchecked("admin1"), vote.accept_msg().unwrap())
+            .unwrap();
+        assert_eq!(vote.status(&app.wrap()).unwrap().status, VoteStatus::Passed);
+        assert_eq!(
+            admin.proposal(&app.wrap(), 1).unwrap().status,
+            VoteStatus::Passed
+        );
+        assert_eq!(admin.admins_count(&app.wrap()).unwrap().count, 3);
+
+        app.execute(
+            Addr::unchecked("donor"),
+            admin.donate_msg(coins(3, "eth")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            admin
+                .pending_rewards(&app.wrap(), "new_admin")
+                .unwrap()
+                .rewards,
+            vec![Asset {
+                info: AssetInfo::Native("eth".to_owned()),
+                amount: Uint128::new(1),
+            }]
+        );
+    }
+
+    #[test]
+    fn shared_storage_layout() {
+        let mut app = App::default();
+        let admin_code_id = app.store_code(admin());
+        let vote_code_id = app.store_code(vote());
+
+        let admin = app
+            .instantiate_contract(
+                admin_code_id,
+                Addr::unchecked("owner"),
+                &AdminInstantiateMsg {
+                    admins: members(&["owner", "admin1"]),
+                    donation_denom: "eth".to_owned(),
+                    vote_code_id,
+                    threshold: Threshold::AbsolutePercentage {
+                        percentage: Decimal::percent(100),
+                    },
+                    voting_period: Duration::Time(3600),
+                    proposal_deposit: None,
+                    veto: None,
+                    timelock: None,
+                    guardian: None,
+                    min_admins: None,
+                    remainder_policy: None,
+                    donation_assets: vec![],
+                },
+                &[],
+                "admin",
+                None,
+            )
+            .unwrap();
+
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                admin.clone(),
+                &AdminExecuteMsg::ProposeAdmin {
+                    addr: String::from("new_admin"),
+                    admin_code_id,
+                    voting_period: None,
+                },
+                &[],
+            )
+            .unwrap();
+        let resp: ProposeAdminResp = from_json(resp.data.unwrap()).unwrap();
+        let admin = AdminContract(admin);
+        let vote = VoteContract(resp.vote_addr);
+
+        // Keys are part of deployed contracts state, renaming them breaks raw queries and migrations
+        for key in [
+            storage::vote::VOTE_OWNER_KEY,
+            storage::vote::ACTION_KEY,
+            storage::vote::EXPIRATION_KEY,
+        ] {
+            assert!(app
+                .wrap()
+                .query_wasm_raw(vote.addr(), key.as_bytes())
+                .unwrap()
+                .is_some());
+        }
+
+        // Items written through the owning contracts' state are read back through the shared one
+        let mut store = MockStorage::new();
+        let action = ProposalAction::AddAdmin {
+            addr: String::from("new_admin"),
+        };
+        let expiration = Expiration::AtHeight(100);
+        let info = storage::admin::AdminInfo {
+            joined: Timestamp::from_seconds(10),
+            weight: 2,
+        };
+        contract_vote::state::VOTE_OWNER
+            .save(&mut store, &admin.addr())
+            .unwrap();
+        contract_vote::state::ACTION
+            .save(&mut store, &action)
+            .unwrap();
+        contract_vote::state::EXPIRATION
+            .save(&mut store, &expiration)
+            .unwrap();
+        contract_admin::state::ADMINS
+            .save(&mut store, Addr::unchecked("admin1"), &info)
+            .unwrap();
+
+        assert_eq!(
+            storage::vote::VOTE_OWNER.load(&store).unwrap(),
+            admin.addr()
+        );
+        assert_eq!(storage::vote::ACTION.load(&store).unwrap(), action);
+        assert_eq!(storage::vote::EXPIRATION.load(&store).unwrap(), expiration);
+        assert_eq!(
+            storage::admin::ADMINS
+                .load(&store, Addr::unchecked("admin1"))
+                .unwrap(),
+            info
+        );
+
+        // Raw reads through the shared layout agree with smart queries of the owning contract
+        assert_eq!(vote.owner(&app.wrap()).unwrap(), admin.addr());
+        assert_eq!(
+            storage::vote::ACTION
+                .query(&app.wrap(), vote.addr())
+                .unwrap(),
+            vote.action(&app.wrap()).unwrap().action
+        );
+        assert_eq!(
+            storage::vote::EXPIRATION
+                .query(&app.wrap(), vote.addr())
+                .unwrap(),
+            vote.status(&app.wrap()).unwrap().expiration
+        );
+
+        let info = admin
+            .admin_info(&app.wrap(), &Addr::unchecked("admin1"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            info.joined,
+            admin.join_time(&app.wrap(), "admin1").unwrap().joined
+        );
+        assert_eq!(
+            info.weight,
+            admin.voting_power(&app.wrap(), "admin1").unwrap().weight
+        );
+        assert_eq!(
+            admin
+                .admin_info(&app.wrap(), &Addr::unchecked("user"))
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn query_response_schemas() {
+        let admin_responses = AdminQueryMsg::response_schemas().unwrap();
+        assert_eq!(admin_responses.len(), 12);
+        assert_eq!(admin_responses["config"], schema_for!(ConfigResp),);
+        assert_eq!(
+            admin_responses["list_proposals"],
+            schema_for!(ProposalListResp),
+        );
+
+        let vote_responses = VoteQueryMsg::response_schemas().unwrap();
+        assert_eq!(vote_responses.len(), 6);
+        assert_eq!(vote_responses["status"], schema_for!(StatusResp));
+        assert_eq!(vote_responses["voters"], schema_for!(VotersResp));
+    }
 }